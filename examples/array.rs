@@ -0,0 +1,58 @@
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+use sea_orm_newtype::DeriveNewType;
+
+#[derive(Clone, Debug, PartialEq, DeriveNewType)]
+#[sea_orm_newtype(transparent)]
+pub struct Id<T>(uuid::Uuid, PhantomData<T>);
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Tag;
+
+#[derive(Clone, Debug, PartialEq, DeriveNewType)]
+#[sea_orm_newtype(via_str)]
+pub struct Email(email_address::EmailAddress);
+
+impl FromStr for Email {
+    type Err = email_address::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        email_address::EmailAddress::from_str(s).map(Email)
+    }
+}
+
+impl std::fmt::Display for Email {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, DeriveNewType)]
+#[sea_orm_newtype(rename_all = "snake_case")]
+pub enum Role {
+    Admin,
+    Member,
+}
+
+// -------------------------------------------------------------------------------------------------
+
+use sea_orm::entity::prelude::*;
+
+/// `Vec<NewType>` is stored as a Postgres array.
+#[derive(Clone, Debug, DeriveEntityModel)]
+#[sea_orm(table_name = "foo")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    id: i32,
+    emails: Vec<Email>,
+    tags: Vec<Id<Tag>>,
+    roles: Vec<Role>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+fn main() {}
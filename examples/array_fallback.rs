@@ -0,0 +1,236 @@
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+use sea_orm_newtype::DeriveNewType;
+
+/// `EmailArray` is stored as a JSON array such as `["a@example.com","b@example.com"]`.
+#[derive(Clone, Debug, PartialEq, DeriveNewType)]
+#[sea_orm_newtype(via_str, array_fallback(json))]
+pub struct Email(email_address::EmailAddress);
+
+impl FromStr for Email {
+    type Err = email_address::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        email_address::EmailAddress::from_str(s).map(Email)
+    }
+}
+
+impl std::fmt::Display for Email {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// `Tags<T>` is stored as a string such as `"1,2,3"`.
+#[derive(Clone, Debug, PartialEq, DeriveNewType)]
+#[sea_orm_newtype(transparent, array_fallback(delimiter = ",", name = "Tags"))]
+pub struct Tag<T>(i32, PhantomData<T>);
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Post;
+
+/// `WhenArray` is stored as a JSON array such as `["2024-01-02T03:04:05"]`.
+#[derive(Clone, Debug, PartialEq, DeriveNewType)]
+#[sea_orm_newtype(transparent, array_fallback(json))]
+pub struct When(sea_orm::prelude::DateTime);
+
+/// `MomentArray` is stored as a string such as `"2024-01-02T03:04:05+00:00;2024-01-03T00:00:00+00:00"`.
+#[derive(Clone, Debug, PartialEq, DeriveNewType)]
+#[sea_orm_newtype(transparent, array_fallback(delimiter = ";"))]
+pub struct Moment(sea_orm::prelude::DateTimeUtc);
+
+#[derive(Clone, Debug, PartialEq, DeriveNewType)]
+#[sea_orm_newtype(transparent, array_fallback(delimiter = ","))]
+pub struct Key(uuid::Uuid);
+
+#[derive(Clone, Debug, PartialEq, DeriveNewType)]
+#[sea_orm_newtype(transparent, array_fallback(json))]
+pub struct Score(f64);
+
+/// the element containing the delimiter is quoted such as `"\"a,b\",c"`.
+#[derive(Clone, Debug, PartialEq, DeriveNewType)]
+#[sea_orm_newtype(transparent, array_fallback(delimiter = ","))]
+pub struct Label(String);
+
+/// the element ending with a part of the multi-character delimiter is also quoted such as `"\"a,\",,b"`.
+#[derive(Clone, Debug, PartialEq, DeriveNewType)]
+#[sea_orm_newtype(transparent, array_fallback(delimiter = ",,"))]
+pub struct Word(String);
+
+/// `DigestArray` is stored as a JSON array of hex strings such as `["00ff"]`.
+#[derive(Clone, Debug, PartialEq, DeriveNewType)]
+#[sea_orm_newtype(transparent, array_fallback(json))]
+pub struct Digest(Vec<u8>);
+
+/// null is stored as unquoted `NULL` such as `"a,NULL,\"NULL\""`.
+#[derive(Clone, Debug, PartialEq, DeriveNewType)]
+#[sea_orm_newtype(transparent, skip(nullable), array_fallback(delimiter = ","))]
+pub struct Nickname(Option<String>);
+
+// -------------------------------------------------------------------------------------------------
+
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "foo")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    id: i32,
+    emails: EmailArray,
+    tags: Tags<Post>,
+    whens: WhenArray,
+    moments: MomentArray,
+    keys: KeyArray,
+    scores: ScoreArray,
+    labels: LabelArray,
+    words: WordArray,
+    digests: DigestArray,
+    nicknames: NicknameArray,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+fn main() {}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::fmt::Debug;
+
+    use sea_orm::sea_query::ValueType;
+    use sea_orm::{DatabaseBackend, EntityTrait, MockDatabase, Value};
+
+    use super::*;
+
+    fn round_trip<T: Clone + Debug + PartialEq + Into<Value> + ValueType>(array: T) {
+        assert_eq!(T::try_from(array.clone().into()).unwrap(), array);
+    }
+
+    fn date_time(day: u32) -> sea_orm::prelude::DateTime {
+        sea_orm::prelude::Date::from_ymd_opt(2024, 1, day)
+            .unwrap()
+            .and_hms_micro_opt(3, 4, 5, 678)
+            .unwrap()
+    }
+
+    fn model() -> Model {
+        Model {
+            id: 1,
+            emails: EmailArray(vec![Email::from_str("a@example.com").unwrap()]),
+            tags: Tags(vec![Tag(1, PhantomData), Tag(-2, PhantomData)]),
+            whens: WhenArray(vec![When(date_time(2)), When(date_time(3))]),
+            moments: MomentArray(vec![Moment(date_time(2).and_utc())]),
+            keys: KeyArray(vec![Key(uuid::Uuid::from_u128(1)), Key(uuid::Uuid::nil())]),
+            scores: ScoreArray(vec![Score(1.5), Score(-0.0), Score(f64::INFINITY)]),
+            labels: LabelArray(vec![
+                Label("a,b".to_owned()),
+                Label(String::new()),
+                Label("\"c\"".to_owned()),
+            ]),
+            words: WordArray(vec![Word("a,".to_owned()), Word("b".to_owned())]),
+            digests: DigestArray(vec![Digest(vec![0x00, 0xff]), Digest(vec![])]),
+            nicknames: NicknameArray(vec![
+                Nickname(Some("a".to_owned())),
+                Nickname(None),
+                Nickname(Some("NULL".to_owned())),
+            ]),
+        }
+    }
+
+    #[test]
+    fn values_round_trip() {
+        let model = model();
+        round_trip(model.emails);
+        round_trip(model.tags);
+        round_trip(model.whens);
+        round_trip(model.moments);
+        round_trip(model.keys);
+        round_trip(model.scores);
+        round_trip(model.labels);
+        round_trip(model.words);
+        round_trip(model.digests);
+        round_trip(model.nicknames);
+        round_trip(WordArray(vec![Word(",".to_owned()), Word(",a".to_owned())]));
+        round_trip(NicknameArray(vec![Nickname(None)]));
+        round_trip(LabelArray(vec![]));
+        round_trip(LabelArray(vec![Label(String::new())]));
+    }
+
+    #[test]
+    fn rows_round_trip() {
+        let model = model();
+        let row = BTreeMap::from([
+            ("id".to_owned(), Value::from(model.id)),
+            ("emails".to_owned(), Value::from(model.emails.clone())),
+            ("tags".to_owned(), Value::from(model.tags.clone())),
+            ("whens".to_owned(), Value::from(model.whens.clone())),
+            ("moments".to_owned(), Value::from(model.moments.clone())),
+            ("keys".to_owned(), Value::from(model.keys.clone())),
+            ("scores".to_owned(), Value::from(model.scores.clone())),
+            ("labels".to_owned(), Value::from(model.labels.clone())),
+            ("words".to_owned(), Value::from(model.words.clone())),
+            ("digests".to_owned(), Value::from(model.digests.clone())),
+            ("nicknames".to_owned(), Value::from(model.nicknames.clone())),
+        ]);
+        let db = MockDatabase::new(DatabaseBackend::Sqlite)
+            .append_query_results([vec![row]])
+            .into_connection();
+        let read = futures::executor::block_on(Entity::find().one(&db)).unwrap();
+        assert_eq!(read, Some(model));
+    }
+
+    #[test]
+    fn delimited_elements_are_quoted() {
+        let labels = |labels: &[&str]| {
+            LabelArray(
+                labels
+                    .iter()
+                    .map(|label| Label((*label).to_owned()))
+                    .collect(),
+            )
+        };
+        assert_eq!(Value::from(labels(&["a", "b"])), Value::from("a,b"));
+        assert_eq!(Value::from(labels(&["a,b", "c"])), Value::from("\"a,b\",c"));
+        assert_eq!(
+            Value::from(labels(&["\"a\""])),
+            Value::from("\"\"\"a\"\"\"")
+        );
+        assert_eq!(Value::from(labels(&[])), Value::from(""));
+        assert_eq!(Value::from(labels(&[""])), Value::from("\"\""));
+        assert!(<LabelArray as ValueType>::try_from(Value::from("\"a")).is_err());
+
+        let words = WordArray(vec![Word("a,".to_owned()), Word("b".to_owned())]);
+        assert_eq!(Value::from(words), Value::from("\"a,\",,b"));
+
+        let nicknames = NicknameArray(vec![
+            Nickname(Some("a".to_owned())),
+            Nickname(None),
+            Nickname(Some("NULL".to_owned())),
+        ]);
+        assert_eq!(Value::from(nicknames), Value::from("a,NULL,\"NULL\""));
+    }
+
+    #[test]
+    fn json_elements_are_typed() {
+        let json = |value: Value| match value {
+            Value::Json(Some(json)) => json.to_string(),
+            value => panic!("unexpected {value:?}"),
+        };
+        assert_eq!(
+            json(WhenArray(vec![When(date_time(2))]).into()),
+            r#"["2024-01-02T03:04:05.000678"]"#
+        );
+        assert_eq!(
+            json(ScoreArray(vec![Score(1.5), Score(f64::INFINITY)]).into()),
+            r#"[1.5,"inf"]"#
+        );
+        assert_eq!(
+            json(DigestArray(vec![Digest(vec![0x00, 0xff])]).into()),
+            r#"["00ff"]"#
+        );
+    }
+}
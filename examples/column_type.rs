@@ -0,0 +1,47 @@
+use sea_orm_newtype::DeriveNewType;
+
+/// stored as `varchar(64)`.
+#[derive(Clone, Debug, PartialEq, DeriveNewType)]
+#[sea_orm_newtype(transparent, column_type = "String(Some(64))")]
+pub struct BoundedName(String);
+
+/// stored as `text`.
+#[derive(Clone, Debug, PartialEq, DeriveNewType)]
+#[sea_orm_newtype(transparent, column_type = "Text")]
+pub struct Description(String);
+
+/// stored as `citext` of Postgres.
+#[derive(Clone, Debug, PartialEq, DeriveNewType)]
+#[sea_orm_newtype(transparent, column_type = r#"Custom("citext")"#)]
+pub struct CaseInsensitive(String);
+
+/// stored as `decimal(19, 0)`.
+#[derive(Clone, Copy, Debug, PartialEq, DeriveNewType)]
+#[sea_orm_newtype(
+    transparent,
+    column_type = "Decimal(Some((19, 0)))",
+    array_type = "BigInt"
+)]
+pub struct Cents(i64);
+
+// -------------------------------------------------------------------------------------------------
+
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, DeriveEntityModel)]
+#[sea_orm(table_name = "foo")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    id: i32,
+    name: BoundedName,
+    description: Description,
+    email: CaseInsensitive,
+    price: Cents,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+fn main() {}
@@ -4,33 +4,25 @@ use std::str::FromStr;
 use sea_orm_newtype::DeriveNewType;
 
 #[derive(Clone, Debug, PartialEq, DeriveNewType)]
-#[sea_orm_newtype(try_from_into = "String")]
+#[sea_orm_newtype(
+    try_from_into = "String",
+    derive_conversions(validator = "parse_email", error = "ParseError")
+)]
 pub struct EmailAddress(email_address::EmailAddress);
 
 #[derive(Debug, thiserror::Error)]
 #[error("ParseError")]
 pub struct ParseError;
 
-impl TryFrom<String> for EmailAddress {
-    type Error = ParseError;
-    fn try_from(value: String) -> Result<Self, Self::Error> {
-        Ok(EmailAddress(
-            email_address::EmailAddress::from_str(&value).map_err(|_| ParseError)?,
-        ))
-    }
-}
-
-impl From<EmailAddress> for String {
-    fn from(value: EmailAddress) -> Self {
-        value.0.to_string()
-    }
+fn parse_email(value: String) -> Result<email_address::EmailAddress, ParseError> {
+    email_address::EmailAddress::from_str(&value).map_err(|_| ParseError)
 }
 
 // -------------------------------------------------------------------------------------------------
 
 use sea_orm::entity::prelude::*;
 
-#[derive(Clone, Debug, DeriveEntityModel)]
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
 #[sea_orm(table_name = "foo")]
 pub struct Model {
     #[sea_orm(primary_key)]
@@ -44,3 +36,40 @@ pub enum Relation {}
 impl ActiveModelBehavior for ActiveModel {}
 
 fn main() {}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use sea_orm::{DatabaseBackend, MockDatabase};
+    use sea_orm_newtype::find_source;
+
+    use super::*;
+
+    fn read(email_address: &str) -> Result<Option<Model>, DbErr> {
+        let row = BTreeMap::from([
+            ("id".to_owned(), Value::from(uuid::Uuid::nil())),
+            ("email_address".to_owned(), Value::from(email_address)),
+        ]);
+        let db = MockDatabase::new(DatabaseBackend::Sqlite)
+            .append_query_results([vec![row]])
+            .into_connection();
+        futures::executor::block_on(Entity::find().one(&db))
+    }
+
+    #[test]
+    fn valid_row_is_read() {
+        let model = read("a@example.com").unwrap().unwrap();
+        assert_eq!(
+            model.email_address,
+            EmailAddress::try_from("a@example.com".to_owned()).unwrap()
+        );
+    }
+
+    #[test]
+    fn parse_error_is_found_in_db_err() {
+        let err = read("invalid").unwrap_err();
+        assert!(matches!(err, DbErr::TryIntoErr { .. }));
+        assert!(find_source::<ParseError>(&err).is_some());
+    }
+}
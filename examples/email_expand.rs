@@ -24,61 +24,99 @@ impl TryFrom<String> for EmailAddress {
     }
 }
 
-/// ParseErrorがトレイト境界を満たしているかチェック
-#[allow(non_camel_case_types)]
-struct __Assersion_EmailAddress_TryFrom
-where
-    <EmailAddress as TryFrom<String>>::Error: std::error::Error;
-
 impl From<EmailAddress> for String {
     fn from(value: EmailAddress) -> Self {
         value.0.to_string()
     }
 }
 
-mod __sea_orm_newtype_email_address_mod {
+#[allow(non_snake_case)]
+mod __sea_orm_newtype_EmailAddress {
     use super::*;
+    use sea_orm_newtype as __sea_orm_newtype;
 
-    impl From<EmailAddress> for sea_orm_newtype::Value {
+    impl ::core::convert::From<EmailAddress> for __sea_orm_newtype::Value {
         fn from(value: EmailAddress) -> Self {
-            Into::<String>::into(value).into()
+            ::core::convert::Into::<__sea_orm_newtype::Value>::into(
+                ::core::convert::Into::<String>::into(value),
+            )
         }
     }
 
-    impl sea_orm_newtype::TryGetable for EmailAddress {
-        fn try_get_by<I: sea_orm_newtype::sea_orm::ColIdx>(
-            res: &sea_orm_newtype::sea_orm::QueryResult,
+    /// ParseErrorがトレイト境界を満たしているかチェック
+    #[allow(non_snake_case, dead_code)]
+    fn __AssertionEmailAddressTryFrom() {
+        let _ = __sea_orm_newtype::__std_source::<
+            <EmailAddress as ::core::convert::TryFrom<String>>::Error,
+        >;
+    }
+
+    impl EmailAddress {
+        #[allow(unused_variables, dead_code, clippy::redundant_closure)]
+        fn __try_get_from_base<R, I>(
+            value: String,
+            res: &__sea_orm_newtype::sea_orm::QueryResult,
             index: I,
-        ) -> Result<Self, sea_orm_newtype::sea_orm::TryGetError> {
-            Ok(
-                TryInto::<Self>::try_into(res.try_get_by::<String, I>(index)?)
-                    .map_err(|e| sea_orm_newtype::sea_orm::DbErr::Custom(e.to_string()))?,
-            )
+        ) -> ::core::result::Result<Self, __sea_orm_newtype::sea_orm::TryGetError>
+        where
+            R: __sea_orm_newtype::TryGetable + ::core::fmt::Debug,
+            I: __sea_orm_newtype::sea_orm::ColIdx,
+        {
+            // ParseErrorはsourceとしてDbErr::TryIntoErrに保持される
+            ::core::result::Result::Ok(::core::convert::TryInto::<Self>::try_into(value).map_err(
+                |e| {
+                    __sea_orm_newtype::ConvertError::__try_get_err::<Self, R, I>(
+                        res,
+                        index,
+                        false,
+                        (__sea_orm_newtype::__std_source::<
+                            <EmailAddress as ::core::convert::TryFrom<String>>::Error,
+                        >)(e),
+                    )
+                },
+            )?)
         }
     }
 
-    impl sea_orm_newtype::ValueType for EmailAddress {
+    impl __sea_orm_newtype::TryGetable for EmailAddress {
+        fn try_get_by<I: __sea_orm_newtype::sea_orm::ColIdx>(
+            res: &__sea_orm_newtype::sea_orm::QueryResult,
+            index: I,
+        ) -> ::core::result::Result<Self, __sea_orm_newtype::sea_orm::TryGetError> {
+            let value = <String as __sea_orm_newtype::TryGetable>::try_get_by(res, index)?;
+            Self::__try_get_from_base::<String, I>(value, res, index)
+        }
+    }
+
+    impl __sea_orm_newtype::ValueType for EmailAddress {
         fn try_from(
-            v: sea_orm_newtype::Value,
-        ) -> Result<Self, sea_orm_newtype::sea_query::ValueTypeErr> {
-            TryInto::<Self>::try_into(<String as sea_orm_newtype::ValueType>::try_from(v)?)
-                .map_err(|_| sea_orm_newtype::sea_query::ValueTypeErr)
+            v: __sea_orm_newtype::Value,
+        ) -> ::core::result::Result<Self, __sea_orm_newtype::sea_query::ValueTypeErr> {
+            ::core::convert::TryInto::<Self>::try_into(
+                <String as __sea_orm_newtype::ValueType>::try_from(v)?,
+            )
+            .map_err(|_| __sea_orm_newtype::sea_query::ValueTypeErr)
         }
-        fn type_name() -> String {
-            // <String as sea_orm_newtype::ValueType>::type_name()
-            stringify!(EmailAddress).to_owned()
+        fn type_name() -> ::std::string::String {
+            <String as __sea_orm_newtype::ValueType>::type_name()
         }
-        fn array_type() -> sea_orm::sea_query::ArrayType {
-            <String as sea_orm_newtype::ValueType>::array_type()
+        fn array_type() -> __sea_orm_newtype::sea_query::ArrayType {
+            <String as __sea_orm_newtype::ValueType>::array_type()
         }
-        fn column_type() -> sea_orm_newtype::sea_query::ColumnType {
-            <String as sea_orm_newtype::ValueType>::column_type()
+        fn column_type() -> __sea_orm_newtype::sea_query::ColumnType {
+            <String as __sea_orm_newtype::ValueType>::column_type()
+        }
+    }
+
+    impl __sea_orm_newtype::Nullable for EmailAddress {
+        fn null() -> __sea_orm_newtype::Value {
+            <String as __sea_orm_newtype::Nullable>::null()
         }
     }
 
-    impl sea_orm_newtype::Nullable for EmailAddress {
-        fn null() -> sea_orm_newtype::sea_query::Value {
-            <String as sea_orm_newtype::Nullable>::null()
+    impl __sea_orm_newtype::sea_orm::IntoActiveValue<EmailAddress> for EmailAddress {
+        fn into_active_value(self) -> __sea_orm_newtype::sea_orm::ActiveValue<Self> {
+            __sea_orm_newtype::sea_orm::ActiveValue::Set(self)
         }
     }
 }
@@ -0,0 +1,225 @@
+use std::fmt::Display;
+
+use sea_orm::DbErr;
+use sea_orm_newtype::DeriveNewType;
+
+/// The error implementing only `Display`.
+#[derive(Debug)]
+pub struct TooLong;
+
+impl Display for TooLong {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "too long")
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, DeriveNewType)]
+#[sea_orm_newtype(try_from_into = "String", error_kind = "display")]
+pub struct Title(String);
+
+impl TryFrom<String> for Title {
+    type Error = TooLong;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        if value.chars().count() > 100 {
+            Err(TooLong)
+        } else {
+            Ok(Title(value))
+        }
+    }
+}
+
+impl From<Title> for String {
+    fn from(value: Title) -> Self {
+        value.0
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// The error converting into `DbErr`.
+#[derive(Debug)]
+pub enum AppError {
+    Negative,
+}
+
+impl From<AppError> for DbErr {
+    fn from(value: AppError) -> Self {
+        match value {
+            AppError::Negative => DbErr::Custom("negative".to_string()),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, DeriveNewType)]
+#[sea_orm_newtype(try_from_into = "i32", error_kind = "into_db_err", primary_key)]
+pub struct Count(i32);
+
+impl TryFrom<i32> for Count {
+    type Error = AppError;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        if value < 0 {
+            Err(AppError::Negative)
+        } else {
+            Ok(Count(value))
+        }
+    }
+}
+
+impl From<Count> for i32 {
+    fn from(value: Count) -> Self {
+        value.0
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[derive(Clone, Copy, Debug, PartialEq, DeriveNewType)]
+#[sea_orm_newtype(try_from_into = "i32", map_err = "percent_err")]
+pub struct Percent(i32);
+
+fn percent_err(value: i32) -> DbErr {
+    DbErr::Custom(format!("{value} is not a percent"))
+}
+
+impl TryFrom<i32> for Percent {
+    type Error = i32;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        if (0..=100).contains(&value) {
+            Ok(Percent(value))
+        } else {
+            Err(value)
+        }
+    }
+}
+
+impl From<Percent> for i32 {
+    fn from(value: Percent) -> Self {
+        value.0
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// The raw value is not shown in the error.
+#[derive(Clone, Debug, PartialEq, DeriveNewType)]
+#[sea_orm_newtype(try_from_into = "String", error_kind = "display", redact)]
+pub struct Pin(String);
+
+impl TryFrom<String> for Pin {
+    type Error = &'static str;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        if value.len() == 4 && value.bytes().all(|b| b.is_ascii_digit()) {
+            Ok(Pin(value))
+        } else {
+            Err("not 4 digits")
+        }
+    }
+}
+
+impl From<Pin> for String {
+    fn from(value: Pin) -> Self {
+        value.0
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, DeriveEntityModel)]
+#[sea_orm(table_name = "foo")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    id: i32,
+    title: Title,
+    count: Count,
+    percent: Percent,
+    pin: Pin,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+fn main() {}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use sea_orm::{DatabaseBackend, EntityTrait, MockDatabase, Value};
+    use sea_orm_newtype::{find_source, ConvertError};
+
+    use super::*;
+
+    fn read(title: &str, count: i32, pin: &str) -> Result<Option<Model>, DbErr> {
+        let row = BTreeMap::from([
+            ("id".to_owned(), Value::from(1)),
+            ("title".to_owned(), Value::from(title)),
+            ("count".to_owned(), Value::from(count)),
+            ("percent".to_owned(), Value::from(50)),
+            ("pin".to_owned(), Value::from(pin)),
+        ]);
+        let db = MockDatabase::new(DatabaseBackend::Sqlite)
+            .append_query_results([vec![row]])
+            .into_connection();
+        futures::executor::block_on(Entity::find().one(&db))
+    }
+
+    #[test]
+    fn valid_row_is_read() {
+        let model = read("title", 3, "1234").unwrap().unwrap();
+        assert_eq!(model.title, Title("title".to_owned()));
+        assert_eq!(model.count, Count(3));
+    }
+
+    #[test]
+    fn display_error_is_kept_with_column() {
+        let err = read(&"a".repeat(101), 3, "1234").unwrap_err();
+
+        let convert_error = find_source::<ConvertError>(&err).unwrap();
+        assert_eq!(convert_error.column(), "title");
+        assert!(convert_error
+            .to_string()
+            .starts_with("column `title` (value: "));
+        assert!(convert_error.to_string().ends_with(": too long"));
+    }
+
+    #[test]
+    fn into_db_err_is_returned_as_it_is() {
+        let err = read("title", -1, "1234").unwrap_err();
+        assert!(matches!(err, DbErr::Custom(message) if message == "negative"));
+    }
+
+    #[test]
+    fn value_is_previewed() {
+        let err = read(&"a".repeat(101), 3, "1234").unwrap_err();
+
+        // the preview is the `Debug` of the value cut at 64 characters
+        let preview = format!("\"{}…", "a".repeat(63));
+        let convert_error = find_source::<ConvertError>(&err).unwrap();
+        assert_eq!(convert_error.value(), Some(preview.as_str()));
+        assert_eq!(
+            convert_error.to_string(),
+            format!("column `title` (value: {preview}): too long")
+        );
+    }
+
+    #[test]
+    fn redacted_value_is_hidden() {
+        let err = read("title", 3, "secret").unwrap_err();
+
+        let convert_error = find_source::<ConvertError>(&err).unwrap();
+        assert_eq!(convert_error.value(), None);
+        assert_eq!(
+            convert_error.to_string(),
+            "column `pin` (value: <redacted>): not 4 digits"
+        );
+        assert!(!format!("{err:?}").contains("secret"));
+    }
+}
@@ -0,0 +1,61 @@
+use std::fmt::Display;
+use std::str::FromStr;
+use std::time::SystemTime;
+
+use sea_orm_newtype::DeriveNewType;
+
+/// bounds such as `T: ValueType` are inferred from the base field `T`.
+#[derive(Debug, Clone, PartialEq, DeriveNewType)]
+#[sea_orm_newtype(transparent)]
+pub struct Wrapper<T>(T);
+
+/// only the base field is stored.
+#[derive(Debug, Clone, PartialEq, DeriveNewType)]
+#[sea_orm_newtype(transparent)]
+pub struct Audited<T> {
+    #[sea_orm_newtype(base)]
+    value: T,
+    read_at: Option<SystemTime>,
+}
+
+/// the base type `String` does not mention `T`, so the bounds are given explicitly.
+#[derive(Debug, Clone, PartialEq, DeriveNewType)]
+#[sea_orm_newtype(
+    via_str,
+    bound = "T: FromStr + Display, T::Err: std::error::Error + Send + Sync + 'static"
+)]
+pub struct Trimmed<T>(T);
+
+impl<T: FromStr> FromStr for Trimmed<T> {
+    type Err = T::Err;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.trim().parse().map(Trimmed)
+    }
+}
+
+impl<T: Display> Display for Trimmed<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, DeriveEntityModel)]
+#[sea_orm(table_name = "foo")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    id: i32,
+    name: Wrapper<String>,
+    nickname: Option<Wrapper<String>>,
+    score: Audited<i64>,
+    age: Trimmed<u8>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+fn main() {}
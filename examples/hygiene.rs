@@ -0,0 +1,89 @@
+/// the facade crate re-exporting `sea_orm_newtype`.
+mod facade {
+    pub use sea_orm_newtype as newtype;
+    pub use sea_orm_newtype::DeriveNewType;
+}
+
+/// the generated code does not depend on the names shadowed in the module.
+mod shadowed {
+    #![allow(dead_code)]
+
+    use crate::facade::DeriveNewType;
+
+    pub type Result<T> = std::result::Result<T, ()>;
+    pub struct Value;
+    pub struct Ok;
+    pub struct Err;
+    pub struct String;
+    pub trait From {}
+    pub trait Into {}
+    pub trait TryFrom {}
+    pub trait TryInto {}
+
+    /// the blanket methods conflicting with the trait methods used by the generated code.
+    pub trait Conflict: Sized {
+        fn into(self) {}
+        fn into_iter(self) {}
+        fn map(self) {}
+        fn collect(self) {}
+        fn iter(&self) {}
+        fn join(&self) {}
+        #[allow(clippy::wrong_self_convention)]
+        fn as_str(self) {}
+        fn to_owned(&self) {}
+        fn to_string(&self) {}
+    }
+
+    impl<T> Conflict for T {}
+
+    #[derive(Clone, Debug, PartialEq, DeriveNewType)]
+    #[sea_orm_newtype(from_into = "i32", crate = "crate::facade::newtype", primary_key)]
+    pub struct Count(i32);
+
+    impl std::convert::From<i32> for Count {
+        fn from(value: i32) -> Self {
+            Count(value)
+        }
+    }
+
+    impl std::convert::From<Count> for i32 {
+        fn from(value: Count) -> Self {
+            value.0
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq, DeriveNewType)]
+    #[sea_orm_newtype(transparent, crate = "crate::facade::newtype", array_fallback(json))]
+    pub struct Name(std::string::String);
+
+    #[derive(Clone, Debug, PartialEq, DeriveNewType)]
+    #[sea_orm_newtype(transparent, crate = "crate::facade::newtype", type_name)]
+    pub struct Id<T>(i64, std::marker::PhantomData<T>);
+
+    #[derive(Clone, Debug, PartialEq, DeriveNewType)]
+    #[sea_orm_newtype(via_str, crate = "crate::facade::newtype")]
+    pub struct Code(u32);
+
+    impl std::str::FromStr for Code {
+        type Err = std::num::ParseIntError;
+
+        fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+            s.parse().map(Code)
+        }
+    }
+
+    impl std::fmt::Display for Code {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            self.0.fmt(f)
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq, DeriveNewType)]
+    #[sea_orm_newtype(crate = "crate::facade::newtype")]
+    pub enum Status {
+        Active,
+        Inactive,
+    }
+}
+
+fn main() {}
@@ -4,21 +4,9 @@ use uuid::Uuid;
 use sea_orm_newtype::DeriveNewType;
 
 #[derive(Debug, Clone, PartialEq, DeriveNewType)]
-#[sea_orm_newtype(from_into = "Uuid", primary_key)]
+#[sea_orm_newtype(from_into = "Uuid", primary_key, derive_conversions)]
 pub struct Id<T>(Uuid, PhantomData<T>);
 
-impl<T> From<Uuid> for Id<T> {
-    fn from(id: Uuid) -> Id<T> {
-        Id(id, PhantomData)
-    }
-}
-
-impl<T> From<Id<T>> for Uuid {
-    fn from(value: Id<T>) -> Self {
-        value.0
-    }
-}
-
 use sea_orm::entity::prelude::*;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -16,53 +16,85 @@ impl<T> From<Id<T>> for Uuid {
     }
 }
 
-mod __sea_orm_newtype_id_mod {
+#[allow(non_snake_case)]
+mod __sea_orm_newtype_Id {
     use super::*;
+    use sea_orm_newtype as __sea_orm_newtype;
 
-    impl<T> From<Id<T>> for sea_orm_newtype::Value {
+    impl<T> ::core::convert::From<Id<T>> for __sea_orm_newtype::Value {
         fn from(value: Id<T>) -> Self {
-            Into::<Uuid>::into(value).into()
+            ::core::convert::Into::<__sea_orm_newtype::Value>::into(
+                ::core::convert::Into::<Uuid>::into(value),
+            )
         }
     }
 
-    impl<T> sea_orm_newtype::TryGetable for Id<T> {
-        fn try_get_by<I: sea_orm_newtype::sea_orm::ColIdx>(
-            res: &sea_orm_newtype::sea_orm::QueryResult,
+    impl<T> Id<T> {
+        #[allow(
+            unused_variables,
+            dead_code,
+            clippy::redundant_closure,
+            clippy::extra_unused_type_parameters
+        )]
+        fn __try_get_from_base<R, I>(
+            value: Uuid,
+            res: &__sea_orm_newtype::sea_orm::QueryResult,
             index: I,
-        ) -> Result<Self, sea_orm_newtype::sea_orm::TryGetError> {
-            Ok(Into::<Self>::into(res.try_get_by::<Uuid, I>(index)?))
+        ) -> ::core::result::Result<Self, __sea_orm_newtype::sea_orm::TryGetError>
+        where
+            R: __sea_orm_newtype::TryGetable + ::core::fmt::Debug,
+            I: __sea_orm_newtype::sea_orm::ColIdx,
+        {
+            ::core::result::Result::Ok(::core::convert::Into::<Self>::into(value))
         }
     }
 
-    impl<T> sea_orm_newtype::ValueType for Id<T> {
+    impl<T> __sea_orm_newtype::TryGetable for Id<T> {
+        fn try_get_by<I: __sea_orm_newtype::sea_orm::ColIdx>(
+            res: &__sea_orm_newtype::sea_orm::QueryResult,
+            index: I,
+        ) -> ::core::result::Result<Self, __sea_orm_newtype::sea_orm::TryGetError> {
+            let value = <Uuid as __sea_orm_newtype::TryGetable>::try_get_by(res, index)?;
+            Self::__try_get_from_base::<Uuid, I>(value, res, index)
+        }
+    }
+
+    impl<T> __sea_orm_newtype::ValueType for Id<T> {
         fn try_from(
-            v: sea_orm_newtype::Value,
-        ) -> Result<Self, sea_orm_newtype::sea_query::ValueTypeErr> {
-            Ok(Into::<Self>::into(
-                <Uuid as sea_orm_newtype::ValueType>::try_from(v)?,
+            v: __sea_orm_newtype::Value,
+        ) -> ::core::result::Result<Self, __sea_orm_newtype::sea_query::ValueTypeErr> {
+            ::core::result::Result::Ok(::core::convert::Into::<Self>::into(
+                <Uuid as __sea_orm_newtype::ValueType>::try_from(v)?,
             ))
         }
-        fn type_name() -> String {
-            <Uuid as sea_orm_newtype::ValueType>::type_name()
+        fn type_name() -> ::std::string::String {
+            <Uuid as __sea_orm_newtype::ValueType>::type_name()
         }
-        fn array_type() -> sea_orm_newtype::sea_query::ArrayType {
-            <Uuid as sea_orm_newtype::ValueType>::array_type()
+        fn array_type() -> __sea_orm_newtype::sea_query::ArrayType {
+            <Uuid as __sea_orm_newtype::ValueType>::array_type()
         }
-        fn column_type() -> sea_orm_newtype::sea_query::ColumnType {
-            <Uuid as sea_orm_newtype::ValueType>::column_type()
+        fn column_type() -> __sea_orm_newtype::sea_query::ColumnType {
+            <Uuid as __sea_orm_newtype::ValueType>::column_type()
+        }
+    }
+
+    impl<T> __sea_orm_newtype::Nullable for Id<T> {
+        fn null() -> __sea_orm_newtype::Value {
+            <Uuid as __sea_orm_newtype::Nullable>::null()
         }
     }
 
-    impl<T> sea_orm_newtype::Nullable for Id<T> {
-        fn null() -> sea_orm_newtype::Value {
-            <Uuid as sea_orm_newtype::Nullable>::null()
+    impl<T> __sea_orm_newtype::sea_orm::IntoActiveValue<Id<T>> for Id<T> {
+        fn into_active_value(self) -> __sea_orm_newtype::sea_orm::ActiveValue<Self> {
+            __sea_orm_newtype::sea_orm::ActiveValue::Set(self)
         }
     }
 
-    impl<T> sea_orm_newtype::TryFromU64 for Id<T> {
-        fn try_from_u64(n: u64) -> Result<Self, sea_orm_newtype::sea_orm::DbErr> {
-            Ok(Into::<Self>::into(
-                <Uuid as sea_orm_newtype::TryFromU64>::try_from_u64(n)?,
+    impl<T> __sea_orm_newtype::TryFromU64 for Id<T> {
+        #[allow(clippy::redundant_closure)]
+        fn try_from_u64(n: u64) -> ::core::result::Result<Self, __sea_orm_newtype::sea_orm::DbErr> {
+            ::core::result::Result::Ok(::core::convert::Into::<Self>::into(
+                (__sea_orm_newtype::__try_from_u64::<Uuid>)(n)?,
             ))
         }
     }
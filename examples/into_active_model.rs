@@ -0,0 +1,34 @@
+use sea_orm_newtype::DeriveNewType;
+
+#[derive(Clone, Debug, PartialEq, DeriveNewType)]
+pub struct Name(String);
+
+#[derive(Clone, Copy, Debug, PartialEq, DeriveNewType)]
+pub struct Age(i32);
+
+// -------------------------------------------------------------------------------------------------
+
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, DeriveEntityModel)]
+#[sea_orm(table_name = "foo")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    id: i32,
+    name: Name,
+    age: Option<Age>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+/// Input struct converted into `ActiveModel` by `IntoActiveValue` of each field.
+#[derive(Clone, Debug, DeriveIntoActiveModel)]
+pub struct NewUser {
+    name: Name,
+    age: Option<Age>,
+}
+
+fn main() {}
@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+
+use sea_orm_newtype::DeriveNewType;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Channel {
+    Email,
+    Push,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, DeriveNewType)]
+#[sea_orm_newtype(json(binary))]
+pub struct NotificationPrefs(Vec<Channel>);
+
+/// JSON can not have non-string keys, so `Serialize` fails for some values.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, DeriveNewType)]
+#[sea_orm_newtype(json)]
+pub struct Quota(std::collections::BTreeMap<(u8, u8), u32>);
+
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, DeriveEntityModel)]
+#[sea_orm(table_name = "foo")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    id: uuid::Uuid,
+    notification_prefs: NotificationPrefs,
+    quota: Option<Quota>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+fn main() {}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use sea_orm::sea_query::ValueType;
+
+    use super::*;
+
+    #[test]
+    fn value_round_trips() {
+        let prefs = NotificationPrefs(vec![Channel::Email, Channel::Push]);
+        assert_eq!(
+            <NotificationPrefs as ValueType>::try_from(Value::from(prefs.clone())).unwrap(),
+            prefs
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "failed to serialize `Quota` into JSON")]
+    fn serialization_error_is_not_written_as_null() {
+        let _ = Value::from(Quota(BTreeMap::from([((1, 2), 3)])));
+    }
+}
@@ -0,0 +1,85 @@
+use sea_orm_newtype::DeriveNewType;
+
+/// stored as trimmed and lowercased, so `" Foo@Example.com"` and `"foo@example.com"` are the same.
+#[derive(Clone, Debug, PartialEq, DeriveNewType)]
+#[sea_orm_newtype(transparent, normalize(trim, lowercase))]
+pub struct Email(String);
+
+/// stored as NFC, so the composed and decomposed forms of `"é"` are the same.
+#[cfg(feature = "unicode")]
+#[derive(Clone, Debug, PartialEq, DeriveNewType)]
+#[sea_orm_newtype(transparent, normalize(nfc))]
+pub struct DisplayName(String);
+
+/// normalized by the function taking and returning the base type.
+#[derive(Clone, Debug, PartialEq, DeriveNewType)]
+#[sea_orm_newtype(from_into = "String", normalize = "normalize_tag")]
+pub struct Tag(String);
+
+fn normalize_tag(value: String) -> String {
+    value.trim().trim_start_matches('#').to_lowercase()
+}
+
+impl From<String> for Tag {
+    fn from(value: String) -> Self {
+        Tag(value)
+    }
+}
+
+impl From<Tag> for String {
+    fn from(value: Tag) -> Self {
+        value.0
+    }
+}
+
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, DeriveEntityModel)]
+#[sea_orm(table_name = "foo")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    id: i32,
+    #[sea_orm(unique)]
+    email: Email,
+    tag: Option<Tag>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+fn main() {}
+
+#[cfg(test)]
+mod tests {
+    use sea_orm::sea_query::ValueType;
+
+    use super::*;
+
+    #[test]
+    fn canonical_value_is_written() {
+        assert_eq!(
+            Value::from(Email(" Foo@Example.com\n".to_owned())),
+            Value::from("foo@example.com")
+        );
+        assert_eq!(Value::from(Tag(" #Rust ".to_owned())), Value::from("rust"));
+    }
+
+    #[test]
+    fn read_value_is_not_normalized() {
+        assert_eq!(
+            <Email as ValueType>::try_from(Value::from(" Foo@Example.com")).unwrap(),
+            Email(" Foo@Example.com".to_owned())
+        );
+    }
+
+    #[cfg(feature = "unicode")]
+    #[test]
+    fn nfc_value_is_written() {
+        assert_eq!(
+            Value::from(DisplayName("e\u{301}".to_owned())),
+            Value::from("\u{e9}")
+        );
+    }
+}
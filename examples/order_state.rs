@@ -0,0 +1,85 @@
+use sea_orm_newtype::DeriveNewType;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, DeriveNewType)]
+#[repr(i16)]
+pub enum OrderState {
+    Cancelled = -1,
+    Pending = 0,
+    Paid = 1,
+    Shipped = 2,
+    /// holds codes written by newer services.
+    #[sea_orm_newtype(other)]
+    Unknown(i16),
+}
+
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "foo")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    id: uuid::Uuid,
+    order_state: OrderState,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+fn main() {}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use sea_orm::sea_query::ValueType;
+    use sea_orm::{DatabaseBackend, MockDatabase};
+
+    use super::*;
+
+    #[test]
+    fn variants_are_stored_as_discriminants() {
+        for (order_state, value) in [
+            (OrderState::Cancelled, -1_i16),
+            (OrderState::Pending, 0),
+            (OrderState::Paid, 1),
+            (OrderState::Shipped, 2),
+        ] {
+            assert_eq!(Value::from(order_state), Value::from(value));
+            assert_eq!(
+                <OrderState as ValueType>::try_from(Value::from(value)).unwrap(),
+                order_state
+            );
+        }
+    }
+
+    #[test]
+    fn unknown_discriminant_is_kept_in_other() {
+        assert_eq!(Value::from(OrderState::Unknown(7)), Value::from(7_i16));
+        assert_eq!(
+            <OrderState as ValueType>::try_from(Value::from(7_i16)).unwrap(),
+            OrderState::Unknown(7)
+        );
+    }
+
+    #[test]
+    fn unknown_discriminant_is_read_from_row() {
+        let id = uuid::Uuid::nil();
+        let row = BTreeMap::from([
+            ("id".to_owned(), Value::from(id)),
+            ("order_state".to_owned(), Value::from(-3_i16)),
+        ]);
+        let db = MockDatabase::new(DatabaseBackend::Sqlite)
+            .append_query_results([vec![row]])
+            .into_connection();
+        let model = futures::executor::block_on(Entity::find().one(&db)).unwrap();
+        assert_eq!(
+            model,
+            Some(Model {
+                id,
+                order_state: OrderState::Unknown(-3),
+            })
+        );
+    }
+}
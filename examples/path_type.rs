@@ -0,0 +1,66 @@
+use sea_orm_newtype::DeriveNewType;
+
+#[derive(Debug, Clone, PartialEq, DeriveNewType)]
+#[sea_orm_newtype(from_into = "uuid::Uuid", primary_key)]
+pub struct UserId(uuid::Uuid);
+
+impl From<uuid::Uuid> for UserId {
+    fn from(value: uuid::Uuid) -> Self {
+        UserId(value)
+    }
+}
+
+impl From<UserId> for uuid::Uuid {
+    fn from(value: UserId) -> Self {
+        value.0
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, DeriveNewType)]
+#[sea_orm_newtype(try_from_into = "Vec<u8>")]
+pub struct Hash([u8; 32]);
+
+#[derive(Debug, thiserror::Error)]
+#[error("invalid hash length: {0}")]
+pub struct HashLengthError(usize);
+
+impl TryFrom<Vec<u8>> for Hash {
+    type Error = HashLengthError;
+    fn try_from(value: Vec<u8>) -> Result<Self, Self::Error> {
+        let len = value.len();
+        Ok(Hash(value.try_into().map_err(|_| HashLengthError(len))?))
+    }
+}
+
+impl From<Hash> for Vec<u8> {
+    fn from(value: Hash) -> Self {
+        value.0.to_vec()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, DeriveNewType)]
+#[sea_orm_newtype(transparent)]
+pub struct Blob(Vec<u8>);
+
+#[derive(Debug, Clone, PartialEq, DeriveNewType)]
+#[sea_orm_newtype(transparent)]
+pub struct When(sea_orm::prelude::DateTime);
+
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, DeriveEntityModel)]
+#[sea_orm(table_name = "foo")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    id: UserId,
+    hash: Hash,
+    blob: Blob,
+    when: When,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+fn main() {}
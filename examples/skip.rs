@@ -0,0 +1,51 @@
+use sea_orm::{ColIdx, QueryResult, TryGetError, TryGetable};
+use sea_orm_newtype::DeriveNewType;
+
+/// `TryGetable` is implemented manually to read the legacy rows stored as `"yes"` or `"no"`.
+#[derive(Clone, Copy, Debug, PartialEq, DeriveNewType)]
+#[sea_orm_newtype(from_into = "bool", skip(try_getable))]
+pub struct Enabled(bool);
+
+impl From<bool> for Enabled {
+    fn from(value: bool) -> Self {
+        Enabled(value)
+    }
+}
+
+impl From<Enabled> for bool {
+    fn from(value: Enabled) -> Self {
+        value.0
+    }
+}
+
+impl TryGetable for Enabled {
+    fn try_get_by<I: ColIdx>(res: &QueryResult, index: I) -> Result<Self, TryGetError> {
+        if let Ok(value) = bool::try_get_by(res, index) {
+            return Ok(Enabled(value));
+        }
+        match String::try_get_by(res, index)?.as_str() {
+            "yes" => Ok(Enabled(true)),
+            "no" => Ok(Enabled(false)),
+            other => Err(TryGetError::DbErr(sea_orm::DbErr::Type(format!(
+                "invalid legacy value: {other}"
+            )))),
+        }
+    }
+}
+
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, DeriveEntityModel)]
+#[sea_orm(table_name = "foo")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    id: i32,
+    enabled: Enabled,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+fn main() {}
@@ -0,0 +1,55 @@
+use sea_orm_newtype::DeriveNewType;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, DeriveNewType)]
+#[sea_orm_newtype(rename_all = "snake_case")]
+pub enum Status {
+    Active,
+    Suspended,
+    #[sea_orm_newtype(value = "closed_by_admin")]
+    Closed,
+}
+
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, DeriveEntityModel)]
+#[sea_orm(table_name = "foo")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    id: uuid::Uuid,
+    status: Status,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+fn main() {}
+
+#[cfg(test)]
+mod tests {
+    use sea_orm::sea_query::ValueType;
+
+    use super::*;
+
+    #[test]
+    fn variants_are_stored_as_strings() {
+        for (status, value) in [
+            (Status::Active, "active"),
+            (Status::Suspended, "suspended"),
+            (Status::Closed, "closed_by_admin"),
+        ] {
+            assert_eq!(Value::from(status), Value::from(value));
+            assert_eq!(
+                <Status as ValueType>::try_from(Value::from(value)).unwrap(),
+                status
+            );
+        }
+    }
+
+    #[test]
+    fn unknown_string_is_rejected() {
+        assert!(<Status as ValueType>::try_from(Value::from("closed")).is_err());
+        assert!(<Status as ValueType>::try_from(Value::from(1)).is_err());
+    }
+}
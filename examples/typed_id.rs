@@ -0,0 +1,59 @@
+use std::marker::PhantomData;
+use uuid::Uuid;
+
+use sea_orm_newtype::DeriveNewType;
+
+/// base field is found automatically. `ValueType::type_name` returns such as `Id<ModelId>`.
+#[derive(Debug, Clone, PartialEq, DeriveNewType)]
+#[sea_orm_newtype(transparent, primary_key, type_name)]
+pub struct Id<T>(Uuid, PhantomData<T>);
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Tag;
+
+/// base field is marked explicitly. `ValueType::type_name` returns `ProductCode`.
+#[derive(Debug, Clone, PartialEq, DeriveNewType)]
+#[sea_orm_newtype(transparent, type_name = "ProductCode")]
+pub struct Code {
+    #[sea_orm_newtype(base)]
+    value: i32,
+    tag: Tag,
+}
+
+use sea_orm::entity::prelude::*;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModelId;
+
+#[derive(Clone, Debug, DeriveEntityModel)]
+#[sea_orm(table_name = "foo")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    id: Id<ModelId>,
+    code: Code,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+fn main() {}
+
+#[cfg(test)]
+mod tests {
+    use sea_orm::sea_query::ValueType;
+
+    use super::*;
+
+    #[test]
+    fn type_name_includes_type_arguments() {
+        assert_eq!(<Id<ModelId> as ValueType>::type_name(), "Id<ModelId>");
+        assert_eq!(<Id<Tag> as ValueType>::type_name(), "Id<Tag>");
+    }
+
+    #[test]
+    fn type_name_is_overridden() {
+        assert_eq!(<Code as ValueType>::type_name(), "ProductCode");
+    }
+}
@@ -0,0 +1,120 @@
+use sea_orm_newtype::DeriveNewType;
+
+/// only the values read from the database are validated.
+#[derive(Clone, Copy, Debug, PartialEq, DeriveNewType)]
+#[sea_orm_newtype(from_into = "i64", validate = "non_negative", primary_key)]
+pub struct Balance(i64);
+
+impl From<i64> for Balance {
+    fn from(value: i64) -> Self {
+        Balance(value)
+    }
+}
+
+impl From<Balance> for i64 {
+    fn from(value: Balance) -> Self {
+        value.0
+    }
+}
+
+fn non_negative(balance: &Balance) -> Result<(), String> {
+    if balance.0 < 0 {
+        Err(format!("negative balance: {}", balance.0))
+    } else {
+        Ok(())
+    }
+}
+
+/// the error can be any type converted into `Box<dyn Error + Send + Sync>`.
+#[derive(Clone, Debug, PartialEq, DeriveNewType)]
+#[sea_orm_newtype(transparent, validate = "Email::check_lowercase")]
+pub struct Email(String);
+
+impl Email {
+    fn check_lowercase(&self) -> Result<(), &'static str> {
+        if self.0.chars().any(char::is_uppercase) {
+            Err("email must be lowercased")
+        } else {
+            Ok(())
+        }
+    }
+}
+
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, DeriveEntityModel)]
+#[sea_orm(table_name = "foo")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    id: i32,
+    balance: Balance,
+    email: Email,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+fn main() {}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use sea_orm::sea_query::ValueType;
+    use sea_orm::{DatabaseBackend, MockDatabase};
+    use sea_orm_newtype::{find_source, ConvertError};
+
+    use super::*;
+
+    fn read(balance: i64, email: &str) -> Result<Option<Model>, DbErr> {
+        let row = BTreeMap::from([
+            ("id".to_owned(), Value::from(1)),
+            ("balance".to_owned(), Value::from(balance)),
+            ("email".to_owned(), Value::from(email)),
+        ]);
+        let db = MockDatabase::new(DatabaseBackend::Sqlite)
+            .append_query_results([vec![row]])
+            .into_connection();
+        futures::executor::block_on(Entity::find().one(&db))
+    }
+
+    #[test]
+    fn valid_row_is_read() {
+        let model = read(10, "a@example.com").unwrap().unwrap();
+        assert_eq!(model.balance, Balance(10));
+        assert_eq!(model.email, Email("a@example.com".to_owned()));
+    }
+
+    #[test]
+    fn validation_error_reaches_db_err() {
+        let err = read(-1, "a@example.com").unwrap_err();
+        let convert_error = find_source::<ConvertError>(&err).unwrap();
+        assert_eq!(convert_error.column(), "balance");
+        assert!(convert_error
+            .to_string()
+            .ends_with(": negative balance: -1"));
+
+        let err = read(10, "A@example.com").unwrap_err();
+        let convert_error = find_source::<ConvertError>(&err).unwrap();
+        assert_eq!(convert_error.column(), "email");
+        assert!(convert_error
+            .to_string()
+            .ends_with(": email must be lowercased"));
+    }
+
+    #[test]
+    fn value_type_is_validated() {
+        assert!(<Balance as ValueType>::try_from(Value::from(-1_i64)).is_err());
+        assert_eq!(
+            <Balance as ValueType>::try_from(Value::from(1_i64)).unwrap(),
+            Balance(1)
+        );
+    }
+
+    #[test]
+    fn written_value_is_not_validated() {
+        assert_eq!(Value::from(Balance(-1)), Value::from(-1_i64));
+    }
+}
@@ -0,0 +1,54 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use sea_orm_newtype::DeriveNewType;
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveNewType)]
+#[sea_orm_newtype(via_str)]
+pub struct Semver {
+    major: u32,
+    minor: u32,
+    patch: u32,
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("invalid semver: {0}")]
+pub struct ParseSemverError(String);
+
+impl FromStr for Semver {
+    type Err = ParseSemverError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseSemverError(s.to_owned());
+        let mut parts = s.splitn(3, '.').map(|part| part.parse::<u32>());
+
+        let mut next = || parts.next().ok_or_else(err)?.map_err(|_| err());
+        Ok(Semver {
+            major: next()?,
+            minor: next()?,
+            patch: next()?,
+        })
+    }
+}
+
+impl Display for Semver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, DeriveEntityModel)]
+#[sea_orm(table_name = "foo")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    id: uuid::Uuid,
+    version: Semver,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+fn main() {}
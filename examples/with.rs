@@ -0,0 +1,37 @@
+use std::net::SocketAddr;
+
+use sea_orm_newtype::DeriveNewType;
+
+#[derive(Clone, Debug, PartialEq, DeriveNewType)]
+#[sea_orm_newtype(with = "socket_addr_string", base_type = "String")]
+pub struct Endpoint(SocketAddr);
+
+/// Store `Endpoint` as a string.
+mod socket_addr_string {
+    use super::Endpoint;
+
+    pub fn to_value(value: &Endpoint) -> String {
+        value.0.to_string()
+    }
+
+    pub fn from_value(value: String) -> Result<Endpoint, std::net::AddrParseError> {
+        Ok(Endpoint(value.parse()?))
+    }
+}
+
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, DeriveEntityModel)]
+#[sea_orm(table_name = "foo")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    id: uuid::Uuid,
+    endpoint: Endpoint,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+fn main() {}
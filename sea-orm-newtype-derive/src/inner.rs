@@ -1,33 +1,341 @@
-use darling::FromDeriveInput;
-use proc_macro2::{Span, TokenStream};
+use darling::{
+    util::{Override, SpannedValue},
+    FromDeriveInput, FromField, FromMeta, FromVariant,
+};
+use ident_case::RenameRule;
+use proc_macro2::{Group, Span, TokenStream, TokenTree};
 use quote::{format_ident, quote};
-use syn::{parse_quote, DeriveInput, Generics, Ident};
+use syn::{
+    parse_quote, punctuated::Punctuated, ConstParam, DeriveInput, Expr, ExprCall, ExprLit,
+    ExprPath, ExprUnary, GenericParam, Generics, Ident, Lit, LitStr, Member, Path, Token, Type,
+    TypeParam, UnOp, WherePredicate,
+};
 
 // -------------------------------------------------------------------------------------------------
 // ConvertType
 
 /// 変換方法を示す列挙体
 pub enum ConvertType {
-    /// from_into = "ident"
-    FromInto(Ident),
-    /// try_from_into = "ident"
-    TryFromInto(Ident),
+    /// from_into = "Type"
+    FromInto(Type),
+    /// try_from_into = "Type"
+    TryFromInto(Type),
     /// transparent
-    Transparent(Ident),
+    Transparent(BaseField),
+    /// with = "module", base_type = "Type"
+    With(Path, Type),
+    /// via_str (Typeは常にString)
+    ViaStr(Type),
+    /// json (Typeは常にserde_json::Value)
+    Json(Type, JsonOptions),
+    /// フィールドの無い列挙体 (TypeはStringもしくはrepr型)
+    Enum(Type, EnumVariants),
 }
 
 use ConvertType::*;
 
 impl ConvertType {
-    fn ident(&self) -> &Ident {
+    /// 経由する型
+    fn base_type(&self) -> &Type {
         match self {
-            FromInto(ident) => ident,
-            TryFromInto(ident) => ident,
-            Transparent(ident) => ident,
+            FromInto(ty) => ty,
+            TryFromInto(ty) => ty,
+            Transparent(field) => &field.ty,
+            With(_, ty) => ty,
+            ViaStr(ty) => ty,
+            Json(ty, _) => ty,
+            Enum(ty, _) => ty,
+        }
+    }
+
+    /// 変換時のエラー型(TryFromとFromStrのみ)．ジェネリクスを持つ場合は型を特定できないので省略する
+    fn error_type(&self, new_type_name: &Ident, generics: &Generics) -> Option<TokenStream> {
+        match self {
+            _ if !generics.params.is_empty() => None,
+            TryFromInto(base_type_name) => {
+                Some(quote! {<#new_type_name as ::core::convert::TryFrom<#base_type_name>>::Error})
+            }
+            ViaStr(_) => Some(quote! {<#new_type_name as ::std::str::FromStr>::Err}),
+            _ => None,
         }
     }
 }
 
+// -------------------------------------------------------------------------------------------------
+// BaseField
+
+/// 変換の際に経由するフィールド
+pub struct BaseField {
+    /// フィールドの型
+    ty: Type,
+    /// フィールド名もしくはインデックス
+    member: Member,
+    /// Defaultで埋めるその他のフィールド
+    others: Vec<Member>,
+}
+
+impl BaseField {
+    /// `value`からフィールドを取り出す式
+    fn access(&self, value: TokenStream) -> TokenStream {
+        let member = &self.member;
+        quote! { #value.#member }
+    }
+
+    /// フィールドの値からNewTypeを構築する式
+    fn construct(&self, new_type_name: &Ident, value: TokenStream) -> TokenStream {
+        let member = &self.member;
+        let others = &self.others;
+        quote! {
+            #new_type_name {
+                #member: #value,
+                #(#others: ::core::default::Default::default(),)*
+            }
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// EnumVariants
+
+/// 列挙体の各ヴァリアントと対応する値
+pub struct EnumVariants {
+    /// 整数として保存する場合のrepr型．Noneの場合は文字列として保存する
+    repr: Option<Ident>,
+    /// ヴァリアント名と値のリテラル
+    variants: Vec<(Ident, TokenStream)>,
+    /// 未知の値をそのまま保持するヴァリアント
+    other: Option<Ident>,
+}
+
+impl EnumVariants {
+    /// `value`を対応する値に変換し，Valueとする式
+    fn encode(&self, new_type_name: &Ident, value: TokenStream) -> TokenStream {
+        let (idents, values): (Vec<_>, Vec<_>) = self.variants.iter().cloned().unzip();
+        let (raw_type, other_raw) = match &self.repr {
+            Some(repr) => (quote! {#repr}, quote! {*raw}),
+            None => (quote! {&str}, quote! {::std::string::String::as_str(raw)}),
+        };
+        let other_arm = self
+            .other
+            .as_ref()
+            .map(|other| quote! { #new_type_name::#other(raw) => #other_raw, });
+        quote! {
+            <#raw_type as ::core::convert::Into<__sea_orm_newtype::Value>>::into(
+                match &#value {
+                    #(#new_type_name::#idents => #values,)*
+                    #other_arm
+                }
+            )
+        }
+    }
+
+    /// 値(Stringもしくはrepr型)からヴァリアントを得る式．対応するヴァリアントが無い場合は`other`のヴァリアントとするか`unknown`を評価する
+    fn decode(
+        &self,
+        new_type_name: &Ident,
+        value: TokenStream,
+        unknown: TokenStream,
+    ) -> TokenStream {
+        let (idents, values): (Vec<_>, Vec<_>) = self.variants.iter().cloned().unzip();
+        let unknown = match &self.other {
+            Some(other) => quote! { ::core::result::Result::Ok(#new_type_name::#other(#value)) },
+            None => unknown,
+        };
+        let scrutinee = match &self.repr {
+            Some(_) => value,
+            None => quote! {::std::string::String::as_str(&#value)},
+        };
+        quote! {
+            match #scrutinee {
+                #(#values => ::core::result::Result::Ok(#new_type_name::#idents),)*
+                _ => #unknown,
+            }
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// ErrorHandling
+
+/// 変換時のエラー型の種類
+#[derive(Debug, Default, Clone, Copy, FromMeta)]
+pub enum ErrorKind {
+    /// std::error::Errorを実装する
+    #[default]
+    #[darling(rename = "std")]
+    Std,
+    /// Displayのみを実装する
+    #[darling(rename = "display")]
+    Display,
+    /// Into<DbErr>を実装する
+    #[darling(rename = "into_db_err")]
+    IntoDbErr,
+}
+
+/// 変換時のエラーの扱い方
+pub enum ErrorHandling {
+    /// エラー型の種類に応じてDbErrに変換する
+    Kind(ErrorKind),
+    /// map_err = "path::fn" (fn(E) -> DbErr)で変換する
+    MapErr(Path),
+}
+
+impl ErrorHandling {
+    /// エラー型にトレイト境界を課してエラーを変換する関数(`error_type`はわかる場合のみ明示する)
+    fn convert_fn(&self, error_type: Option<&TokenStream>) -> Option<TokenStream> {
+        let convert_fn = match (self, error_type) {
+            (ErrorHandling::Kind(ErrorKind::Std), Some(_)) => {
+                quote! {__sea_orm_newtype::__std_source}
+            }
+            // 型がわからない場合は&strやStringも受け付けるInto<Box<dyn Error + Send + Sync>>とする
+            (ErrorHandling::Kind(ErrorKind::Std), None) => return None,
+            (ErrorHandling::Kind(ErrorKind::Display), _) => {
+                quote! {__sea_orm_newtype::__display_source}
+            }
+            (ErrorHandling::Kind(ErrorKind::IntoDbErr), _) => {
+                quote! {__sea_orm_newtype::__into_db_err}
+            }
+            (ErrorHandling::MapErr(_), _) => return None,
+        };
+        Some(match error_type {
+            Some(error_type) => quote! {#convert_fn::<#error_type>},
+            None => convert_fn,
+        })
+    }
+
+    /// エラーをsourceとして保持できる型に変換する式
+    fn source(&self, error_type: Option<&TokenStream>) -> TokenStream {
+        match self.convert_fn(error_type) {
+            Some(convert_fn) => quote! {(#convert_fn)(e)},
+            None => quote! {e},
+        }
+    }
+
+    /// エラー型`error_type`がトレイト境界を満たしているかのアサーション．エラーは`span`の位置に表示される
+    /// (変換時と同じ関数を同じ位置で参照するので，rustcが重複するエラーをまとめて一つだけ表示する)
+    fn assertion(
+        &self,
+        assertion_fn: Ident,
+        error_type: TokenStream,
+        span: Span,
+    ) -> Option<TokenStream> {
+        self.convert_fn(Some(&error_type)).map(|convert_fn| {
+            respan(
+                quote! {
+                    #[allow(non_snake_case, dead_code)]
+                    fn #assertion_fn() {
+                        let _ = #convert_fn;
+                    }
+                },
+                span,
+            )
+        })
+    }
+
+    /// 読み取り時の変換エラーを列や値(`read_type`として再度読み取る)の情報と共にsea_orm_newtype::ConvertErrorとしてDbErrに変換するクロージャ
+    fn try_get_err(
+        &self,
+        read_type: &TokenStream,
+        redact: bool,
+        error_type: Option<&TokenStream>,
+    ) -> TokenStream {
+        let source = self.source(error_type);
+        match self {
+            ErrorHandling::Kind(ErrorKind::IntoDbErr) => quote! {|e| #source},
+            ErrorHandling::Kind(_) => {
+                quote! {
+                    |e| __sea_orm_newtype::ConvertError::__try_get_err::<Self, #read_type, I>(
+                        res, index, #redact, #source,
+                    )
+                }
+            }
+            ErrorHandling::MapErr(map_err) => quote! {#map_err},
+        }
+    }
+
+    /// 変換時のエラーをsourceとして保持したDbErr::TryIntoErrに変換するクロージャ
+    fn try_into_err(&self, base_type_name: &Type, error_type: Option<&TokenStream>) -> TokenStream {
+        let source = self.source(error_type);
+        match self {
+            ErrorHandling::Kind(ErrorKind::IntoDbErr) => quote! {|e| #source},
+            ErrorHandling::Kind(_) => {
+                quote! {
+                    |e| __sea_orm_newtype::sea_orm::DbErr::TryIntoErr {
+                        from: ::std::any::type_name::<#base_type_name>(),
+                        into: ::std::any::type_name::<Self>(),
+                        source: ::core::convert::Into::into(#source),
+                    }
+                }
+            }
+            ErrorHandling::MapErr(map_err) => quote! {#map_err},
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Bounds
+
+/// 生成するimplに課す型引数のトレイト境界
+struct Bounds {
+    /// 元のジェネリクス
+    generics: Generics,
+    /// bound = "..."で明示された境界(指定した場合は推論しない)
+    explicit: Option<Vec<WherePredicate>>,
+    /// 境界を課す経由する型(型引数を含む場合のみ)
+    bounded_type: Option<Type>,
+}
+
+impl Bounds {
+    fn new(generics: &Generics, explicit: Option<Vec<WherePredicate>>, base_type: &Type) -> Self {
+        let type_params = generics
+            .type_params()
+            .map(|param| param.ident.clone())
+            .collect::<Vec<_>>();
+        let bounded_type =
+            contains_ident(quote! {#base_type}, &type_params).then(|| base_type.clone());
+        Bounds {
+            generics: generics.clone(),
+            explicit,
+            bounded_type,
+        }
+    }
+
+    /// 経由する型に`bound`を課したジェネリクス
+    fn with(&self, bound: TokenStream) -> Generics {
+        self.with_predicates(|ty| vec![parse_quote! {#ty: #bound}])
+    }
+
+    /// 経由する型`ty`に対する境界`predicates`を課したジェネリクス
+    fn with_predicates(&self, predicates: impl FnOnce(&Type) -> Vec<WherePredicate>) -> Generics {
+        let predicates = match (&self.explicit, &self.bounded_type) {
+            (Some(explicit), _) => explicit.clone(),
+            (None, Some(ty)) => predicates(ty),
+            (None, None) => vec![],
+        };
+
+        let mut generics = self.generics.clone();
+        if !predicates.is_empty() {
+            generics.make_where_clause().predicates.extend(predicates);
+        }
+        generics
+    }
+}
+
+/// bound = "..."をwhere節の述語の列として解析する(空文字列の場合は境界を課さない)
+fn parse_bound(bound: &LitStr) -> syn::Result<Vec<WherePredicate>> {
+    let predicates = bound.parse_with(Punctuated::<WherePredicate, Token![,]>::parse_terminated)?;
+    Ok(predicates.into_iter().collect())
+}
+
+/// トークン列が`idents`のいずれかを含むかどうか
+fn contains_ident(tokens: TokenStream, idents: &[Ident]) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(ident) => idents.contains(&ident),
+        TokenTree::Group(group) => contains_ident(group.stream(), idents),
+        _ => false,
+    })
+}
+
 // -------------------------------------------------------------------------------------------------
 // InputReceiver
 
@@ -36,18 +344,171 @@ impl ConvertType {
 #[darling(attributes(sea_orm_newtype))]
 struct InputReceiver {
     /// 読み取り時にFrom<A> for NewType，書き出し時にFrom<NewType> for Aで経由する
-    from_into: Option<String>,
+    from_into: Option<SpannedValue<Type>>,
     /// 読み取り時にTryFrom<A> for NewType，書き出し時にFrom<NewType> for Aで経由する
-    try_from_into: Option<String>,
-    /// self.0の型を経由する
+    try_from_into: Option<SpannedValue<Type>>,
+    /// 唯一のフィールドの型を経由する
     #[darling(default)]
-    transparent: bool,
+    transparent: SpannedValue<bool>,
     /// TryFromU64を実装する
     #[darling(default)]
-    primary_key: bool,
-    /// ValueType::type_nameを自身の名前に変更する
+    primary_key: SpannedValue<bool>,
+    /// ValueType::type_nameを自身の名前もしくは指定した文字列に変更する
+    type_name: Option<Override<LitStr>>,
+    /// ValueType::column_typeをColumnTypeのヴァリアントに変更する
+    column_type: Option<LitStr>,
+    /// ValueType::array_typeをArrayTypeのヴァリアントに変更する
+    array_type: Option<LitStr>,
+    /// 読み取り時にmodule::from_value，書き出し時にmodule::to_valueで経由する
+    with: Option<SpannedValue<Path>>,
+    /// withで経由する型
+    base_type: Option<SpannedValue<Type>>,
+    /// 読み取り時にFromStr，書き出し時にDisplayでStringを経由する
+    #[darling(default)]
+    via_str: SpannedValue<bool>,
+    /// 読み取り時にDeserialize，書き出し時にSerializeでserde_json::Valueを経由する
+    json: Option<SpannedValue<Override<JsonOptions>>>,
+    /// 列挙体のヴァリアント名を値に変換する規則
+    rename_all: Option<LitStr>,
+    /// 読み取り時の変換エラーに値を含めない
+    #[darling(default)]
+    redact: bool,
+    /// 変換時のエラー型の種類
+    error_kind: Option<SpannedValue<ErrorKind>>,
+    /// 変換時のエラーをDbErrに変換する関数
+    map_err: Option<SpannedValue<Path>>,
+    /// from_into, try_from_intoで経由するFrom/TryFromとIntoを実装する
+    derive_conversions: Option<SpannedValue<Override<DeriveConversions>>>,
+    /// Vec<NewType>をJSONの配列もしくは区切り文字で連結した文字列として保存する型を生成する
+    array_fallback: Option<SpannedValue<ArrayFallback>>,
+    /// 生成するimplに課すトレイト境界(省略した場合は経由する型から推論する)
+    bound: Option<LitStr>,
+    /// sea_orm_newtypeクレートのパス(ファサードクレートから再エクスポートする場合など)
+    #[darling(rename = "crate")]
+    krate: Option<Path>,
+    /// 生成しないimpl
+    #[darling(default)]
+    skip: Skip,
+    /// 読み取った値を検証する関数 fn(&NewType) -> Result<(), E>
+    validate: Option<Path>,
+    /// 書き出し時に経由する型の値を正規化する
+    normalize: Option<SpannedValue<Normalize>>,
+}
+
+/// skipのオプション(trueのimplは生成せず，利用者が実装する)
+#[derive(Debug, Default, Clone, FromMeta)]
+struct Skip {
+    /// From<NewType> for Value
+    #[darling(default)]
+    from: bool,
+    /// TryGetable
+    #[darling(default)]
+    try_getable: bool,
+    /// ValueType
+    #[darling(default)]
+    value_type: bool,
+    /// Nullable
+    #[darling(default)]
+    nullable: bool,
+    /// IntoActiveValue
+    #[darling(default)]
+    into_active_value: bool,
+}
+
+/// derive_conversionsのオプション
+#[derive(Debug, Default, Clone, FromMeta)]
+struct DeriveConversions {
+    /// TryFrom<A> for NewTypeで用いる関数 fn(A) -> Result<フィールドの型, error>
+    validator: Option<Path>,
+    /// validatorのエラー型
+    error: Option<Type>,
+}
+
+/// array_fallbackのオプション
+#[derive(Debug, Clone, FromMeta)]
+struct ArrayFallback {
+    /// 生成する型の名前(省略した場合は`{NewType}Array`)
+    name: Option<Ident>,
+    /// JSONの配列として保存する
+    #[darling(default)]
+    json: bool,
+    /// 区切り文字で連結した文字列として保存する
+    delimiter: Option<String>,
+}
+
+/// normalizeのオプション
+#[derive(Debug, Clone)]
+enum Normalize {
+    /// normalize = "path::fn" (fn(A) -> A)
+    Function(Path),
+    /// normalize(nfc, trim, lowercase) (Stringのみ)
+    Builtins(NormalizeBuiltins),
+}
+
+impl FromMeta for Normalize {
+    fn from_string(value: &str) -> darling::Result<Self> {
+        Path::from_string(value).map(Normalize::Function)
+    }
+
+    fn from_list(items: &[darling::ast::NestedMeta]) -> darling::Result<Self> {
+        NormalizeBuiltins::from_list(items).map(Normalize::Builtins)
+    }
+}
+
+/// normalizeの組み込みの正規化(nfc, trim, lowercaseの順に適用する)
+#[derive(Debug, Default, Clone, FromMeta)]
+struct NormalizeBuiltins {
+    /// Unicode正規化形式C
+    #[darling(default)]
+    nfc: bool,
+    /// 前後の空白を取り除く
     #[darling(default)]
-    type_name: bool,
+    trim: bool,
+    /// 小文字にする
+    #[darling(default)]
+    lowercase: bool,
+}
+
+/// array_fallbackの保存方法
+enum ArrayEncoding {
+    /// JSONの配列
+    Json,
+    /// 区切り文字で連結した文字列
+    Delimited(String),
+}
+
+/// jsonのオプション
+#[derive(Debug, Default, Clone, FromMeta)]
+pub struct JsonOptions {
+    /// ColumnType::JsonBinaryとする
+    #[darling(default)]
+    binary: bool,
+}
+
+// -------------------------------------------------------------------------------------------------
+// FieldReceiver
+
+/// フィールドアトリビュート
+#[derive(Debug, FromField)]
+#[darling(attributes(sea_orm_newtype))]
+struct FieldReceiver {
+    /// transparentで経由するフィールドとする
+    #[darling(default)]
+    base: bool,
+}
+
+// -------------------------------------------------------------------------------------------------
+// VariantReceiver
+
+/// ヴァリアントアトリビュート
+#[derive(Debug, FromVariant)]
+#[darling(attributes(sea_orm_newtype))]
+struct VariantReceiver {
+    /// ヴァリアントに対応する値
+    value: Option<LitStr>,
+    /// 未知の値をそのまま保持するヴァリアントとする
+    #[darling(default)]
+    other: bool,
 }
 
 // -------------------------------------------------------------------------------------------------
@@ -61,96 +522,679 @@ pub fn derive_newtype_inner(input: &DeriveInput) -> syn::Result<TokenStream> {
         transparent,
         primary_key,
         type_name,
+        column_type,
+        array_type,
+        with,
+        base_type,
+        via_str,
+        json,
+        rename_all,
+        redact,
+        error_kind,
+        map_err,
+        derive_conversions,
+        array_fallback,
+        bound,
+        krate,
+        skip,
+        validate,
+        normalize,
     } = InputReceiver::from_derive_input(input)?;
 
     let new_type_name = &input.ident;
 
-    let convert_type = match (from_into, try_from_into, transparent) {
-        (Some(from_into), None, false) => FromInto(Ident::new(&from_into, Span::call_site())),
-        (None, Some(try_from_into), false) => {
-            TryFromInto(Ident::new(&try_from_into, Span::call_site()))
+    if let (Some(base_type), None) = (&base_type, &with) {
+        return Err(syn::Error::new(
+            base_type.span(),
+            r#"`base_type` can only be used with `with = "module"`."#,
+        ));
+    }
+
+    // (アトリビュート名, 位置, 変換方法)
+    let mut convert_types = [
+        from_into.map(|ty| Ok(("from_into", ty.span(), FromInto((*ty).clone())))),
+        try_from_into.map(|ty| Ok(("try_from_into", ty.span(), TryFromInto((*ty).clone())))),
+        with.map(|with| {
+            base_type
+                .ok_or_else(|| {
+                    syn::Error::new(
+                        with.span(),
+                        r#"`with = "module"` must be set with `base_type = "BaseType"`."#,
+                    )
+                })
+                .map(|base_type| {
+                    (
+                        "with",
+                        with.span(),
+                        With((*with).clone(), (*base_type).clone()),
+                    )
+                })
+        }),
+        via_str.then(|| {
+            Ok((
+                "via_str",
+                via_str.span(),
+                ViaStr(parse_quote!(::std::string::String)),
+            ))
+        }),
+        json.map(|json| {
+            if cfg!(feature = "serde") {
+                Ok((
+                    "json",
+                    json.span(),
+                    Json(
+                        parse_quote!(__sea_orm_newtype::serde_json::Value),
+                        (*json).clone().unwrap_or_default(),
+                    ),
+                ))
+            } else {
+                Err(syn::Error::new(
+                    json.span(),
+                    r#"`json` requires the "serde" feature of sea-orm-newtype."#,
+                ))
+            }
+        }),
+        transparent.then(|| {
+            get_and_check_base_field(input, "transparent", transparent.span())
+                .map(|field| ("transparent", transparent.span(), Transparent(field)))
+        }),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<syn::Result<Vec<_>>>()?;
+
+    if let [(first, _, _), (second, span, _), ..] = convert_types.as_slice() {
+        return Err(syn::Error::new(
+            *span,
+            format!("`{second}` can not be used with `{first}`. Set at most one of `from_into`, `try_from_into`, `transparent`, `with`, `via_str` or `json`."),
+        ));
+    }
+
+    let is_enum = matches!(input.data, syn::Data::Enum(_));
+
+    if let Some(rename_all) = rename_all
+        .as_ref()
+        .filter(|_| !is_enum || !convert_types.is_empty())
+    {
+        return Err(syn::Error::new_spanned(
+            rename_all,
+            "`rename_all` can only be used for enum stored by its variants.",
+        ));
+    }
+
+    // 変換方法に関するエラーを表示する位置
+    let (convert_span, convert_type) = match convert_types.pop() {
+        Some((_, span, convert_type)) => (span, convert_type),
+        None if is_enum => {
+            let variants = get_and_check_enum_variants(input, rename_all.as_ref())?;
+            let base_type = match &variants.repr {
+                Some(repr) => parse_quote!(#repr),
+                None => parse_quote!(::std::string::String),
+            };
+            (new_type_name.span(), Enum(base_type, variants))
         }
-        (None, None, true) | (None, None, false) => {
-            Transparent(get_and_check_transparent_type(input)?)
+        None => (
+            new_type_name.span(),
+            Transparent(get_and_check_base_field(
+                input,
+                "transparent",
+                new_type_name.span(),
+            )?),
+        ),
+    };
+
+    // 変換時にエラーの発生し得ない変換方法ではエラーの扱いを指定できない
+    let fallible = matches!(convert_type, TryFromInto(_) | With(..) | ViaStr(_));
+    let error_handling = match (map_err, error_kind) {
+        (Some(_), Some(error_kind)) => {
+            return Err(syn::Error::new(
+                error_kind.span(),
+                "`error_kind` can not be used with `map_err`.",
+            ))
+        }
+        (Some(map_err), None) if fallible => ErrorHandling::MapErr((*map_err).clone()),
+        (None, Some(error_kind)) if fallible => ErrorHandling::Kind(*error_kind),
+        (None, None) => ErrorHandling::Kind(ErrorKind::default()),
+        (Some(map_err), None) => {
+            return Err(syn::Error::new(
+                map_err.span(),
+                r#"`map_err` can only be used with `try_from_into`, `with` or `via_str`."#,
+            ))
         }
-        _ => {
+        (None, Some(error_kind)) => {
             return Err(syn::Error::new(
-                Span::call_site(),
-                r#"sea_orm_newtype must be set from_into = "BaseType" or try_from_into = "BaseType" or transparent at most one."#,
+                error_kind.span(),
+                r#"`error_kind` can only be used with `try_from_into`, `with` or `via_str`."#,
             ))
         }
     };
 
-    let generics = &input.generics;
+    // 経由する型の値に順に適用する正規化の関数とnormalizeの位置
+    let normalizers = normalize
+        .map(|normalize| {
+            if matches!(convert_type, Json(..) | Enum(..)) {
+                return Err(syn::Error::new(
+                    normalize.span(),
+                    r#"`normalize` can only be used with `from_into`, `try_from_into`, `transparent`, `with` or `via_str`."#,
+                ));
+            }
+            // 正規化はFrom<NewType> for Valueで行うので，手動の実装とは両立しない
+            if skip.from {
+                return Err(syn::Error::new(
+                    normalize.span(),
+                    "`normalize` can not be used with `skip(from)`, because the value is normalized in the generated `From<T> for sea_query::Value`.",
+                ));
+            }
+            let functions = match &*normalize {
+                Normalize::Function(function) => vec![quote! {#function}],
+                Normalize::Builtins(NormalizeBuiltins {
+                    nfc,
+                    trim,
+                    lowercase,
+                }) => {
+                    if *nfc && !cfg!(feature = "unicode") {
+                        return Err(syn::Error::new(
+                            normalize.span(),
+                            r#"`normalize(nfc)` requires the "unicode" feature of sea-orm-newtype."#,
+                        ));
+                    }
+                    let functions = [
+                        nfc.then(|| quote! {__sea_orm_newtype::__normalize_nfc}),
+                        trim.then(|| quote! {__sea_orm_newtype::__normalize_trim}),
+                        lowercase.then(|| quote! {__sea_orm_newtype::__normalize_lowercase}),
+                    ]
+                    .into_iter()
+                    .flatten()
+                    .collect::<Vec<_>>();
+                    if functions.is_empty() {
+                        return Err(syn::Error::new(
+                            normalize.span(),
+                            "`normalize` must be set at least one of `nfc`, `trim` or `lowercase`.",
+                        ));
+                    }
+                    functions
+                }
+            };
+            Ok((functions, normalize.span()))
+        })
+        .transpose()?;
+
+    let array_fallback = array_fallback
+        .map(|fallback| {
+            // 配列の要素は生成した変換で読み取るので，手動のTryGetableとは両立しない
+            if skip.try_getable {
+                return Err(syn::Error::new(
+                    fallback.span(),
+                    "`array_fallback` can not be used with `skip(try_getable)`, because the elements are read without the manual `TryGetable`.",
+                ));
+            }
+            let encoding = match (fallback.json, &fallback.delimiter) {
+                (true, None) => ArrayEncoding::Json,
+                // 要素のクォートに`"`を用いるので区切り文字には含められない
+                (false, Some(delimiter)) if delimiter.contains('"') => {
+                    return Err(syn::Error::new(
+                        fallback.span(),
+                        r#"`delimiter` of `array_fallback` must not contain `"`."#,
+                    ))
+                }
+                (false, Some(delimiter)) if !delimiter.is_empty() => {
+                    ArrayEncoding::Delimited(delimiter.clone())
+                }
+                _ => {
+                    return Err(syn::Error::new(
+                        fallback.span(),
+                        r#"`array_fallback` must be set either `json` or non-empty `delimiter = ","`."#,
+                    ))
+                }
+            };
+            let array_name = fallback
+                .name
+                .clone()
+                .unwrap_or_else(|| format_ident!("{}Array", new_type_name));
+            Ok((array_name, encoding, fallback.span()))
+        })
+        .transpose()?;
+
+    let bounds = Bounds::new(
+        &input.generics,
+        bound.as_ref().map(parse_bound).transpose()?,
+        convert_type.base_type(),
+    );
 
     let mod_name = format_ident!("__sea_orm_newtype_{}", new_type_name);
+    let krate = krate.unwrap_or_else(|| parse_quote! {::sea_orm_newtype});
 
-    let impl_from_newtype_for_value =
-        from_newtype_for_value(new_type_name, &convert_type, generics);
+    let impl_from_newtype_for_value = (!skip.from).then(|| {
+        from_newtype_for_value(
+            new_type_name,
+            &convert_type,
+            &bounds,
+            normalizers
+                .as_ref()
+                .map(|(functions, span)| (functions.as_slice(), *span)),
+        )
+    });
+    let impl_try_get_from_base_for_newtype = try_get_from_base_for_newtype(
+        new_type_name,
+        &convert_type,
+        &bounds,
+        &error_handling,
+        convert_span,
+        redact,
+        validate.as_ref(),
+    );
     let impl_try_getable_for_newtype =
-        try_getable_for_newtype(new_type_name, &convert_type, generics);
-    let impl_value_type_for_newtype =
-        value_type_for_newtype(new_type_name, &convert_type, generics, type_name);
+        (!skip.try_getable).then(|| try_getable_for_newtype(new_type_name, &convert_type, &bounds));
+    let column_type = column_type.as_ref().map(column_type_expr).transpose()?;
+    let array_type = array_type.as_ref().map(array_type_expr).transpose()?;
+    let impl_value_type_for_newtype = (!skip.value_type).then(|| {
+        value_type_for_newtype(
+            new_type_name,
+            &convert_type,
+            &bounds,
+            type_name.as_ref(),
+            column_type,
+            array_type,
+            validate.as_ref(),
+        )
+    });
 
-    let impl_nullable_for_newtype = nullable_for_newtype(new_type_name, &convert_type, generics);
-    let impl_try_from_u64_for_newtype =
-        primary_key.then(|| try_from_u64_for_newtype(new_type_name, &convert_type, generics));
+    let impl_nullable_for_newtype =
+        (!skip.nullable).then(|| nullable_for_newtype(new_type_name, &convert_type, &bounds));
+    let impl_into_active_value_for_newtype = (!skip.into_active_value).then(|| {
+        into_active_value_for_newtype(
+            new_type_name,
+            &bounds.with(quote! {::core::convert::Into<__sea_orm_newtype::Value>}),
+        )
+    });
+    // Vec<NewType>の読み取りも手動のTryGetableに合わせて利用者が実装する
+    let impl_postgres_array_for_newtype = cfg!(feature = "postgres-array").then(|| {
+        postgres_array_for_newtype(new_type_name, &convert_type, &bounds, skip.try_getable)
+    });
+    let impl_try_from_u64_for_newtype = primary_key.then(|| {
+        try_from_u64_for_newtype(
+            new_type_name,
+            &convert_type,
+            &bounds,
+            &error_handling,
+            validate.as_ref(),
+            convert_span,
+            primary_key.span(),
+        )
+    });
+    let (array_fallback_struct, impl_array_fallback_for_newtype) = array_fallback
+        .map(|(array_name, encoding, fallback_span)| {
+            array_fallback_for_newtype(
+                input,
+                &convert_type,
+                &array_name,
+                &encoding,
+                &bounds,
+                redact,
+                fallback_span,
+            )
+        })
+        .unzip();
+    let impl_conversions_for_newtype = derive_conversions
+        .map(|conversions| {
+            conversions_for_newtype(
+                input,
+                &convert_type,
+                &(*conversions).clone().unwrap_or_default(),
+                conversions.span(),
+                &input.generics,
+            )
+        })
+        .transpose()?;
 
     Ok(quote! {
+        #array_fallback_struct
+
         #[allow(non_snake_case)]
         mod #mod_name {
             use super::*;
+            // 生成するコードはこの名前でsea_orm_newtypeを参照する
+            use #krate as __sea_orm_newtype;
 
             #impl_from_newtype_for_value
 
+            #impl_try_get_from_base_for_newtype
+
             #impl_try_getable_for_newtype
 
             #impl_value_type_for_newtype
 
             #impl_nullable_for_newtype
 
+            #impl_into_active_value_for_newtype
+
+            #impl_postgres_array_for_newtype
+
+            #impl_array_fallback_for_newtype
+
             #impl_try_from_u64_for_newtype
+
+            #impl_conversions_for_newtype
+        }
+    })
+}
+
+/// 経由するフィールドを取得．フィールドが一つだけに定まるかどうかチェック．`span`はアトリビュートの位置
+fn get_and_check_base_field(
+    input: &DeriveInput,
+    attr_name: &str,
+    span: Span,
+) -> syn::Result<BaseField> {
+    let syn::Data::Struct(data_struct) = &input.data else {
+        return Err(syn::Error::new(
+            span,
+            format!("`sea_orm_newtype({attr_name})` can only use for struct."),
+        ));
+    };
+
+    let fields = data_struct
+        .fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| {
+            let member = match &field.ident {
+                Some(ident) => Member::Named(ident.clone()),
+                None => Member::Unnamed(i.into()),
+            };
+            Ok((member, field, FieldReceiver::from_field(field)?))
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    // `base`が指定されたフィールドを優先し，無ければPhantomData以外のフィールドを探す
+    let marked = fields
+        .iter()
+        .filter(|(_, _, receiver)| receiver.base)
+        .collect::<Vec<_>>();
+    let candidates = if marked.is_empty() {
+        fields
+            .iter()
+            .filter(|(_, field, _)| !is_phantom_data(&field.ty))
+            .collect::<Vec<_>>()
+    } else {
+        marked
+    };
+
+    match candidates.as_slice() {
+        [(member, field, _)] => Ok(BaseField {
+            ty: field.ty.clone(),
+            member: member.clone(),
+            others: fields
+                .iter()
+                .map(|(member, _, _)| member)
+                .filter(|other| *other != member)
+                .cloned()
+                .collect(),
+        }),
+        [] => Err(syn::Error::new(
+            span,
+            format!("`sea_orm_newtype({attr_name})` can only use for struct thats have a field other than PhantomData."),
+        )),
+        [_, (_, field, _), ..] => Err(syn::Error::new_spanned(
+            field,
+            format!("`sea_orm_newtype({attr_name})` needs exactly one base field. Mark it with `#[sea_orm_newtype(base)]`."),
+        )),
+    }
+}
+
+/// 列挙体のヴァリアントと対応する値を取得．フィールドが無く値が重複しないかどうかチェック
+fn get_and_check_enum_variants(
+    input: &DeriveInput,
+    rename_all: Option<&LitStr>,
+) -> syn::Result<EnumVariants> {
+    let syn::Data::Enum(data_enum) = &input.data else {
+        unreachable!("get_and_check_enum_variants is called only for enum.")
+    };
+
+    let repr = get_integer_repr(input)?;
+
+    let rename_rule = rename_all
+        .map(|rename_all| {
+            rename_all.value().parse::<RenameRule>().map_err(|_| {
+                syn::Error::new_spanned(
+                    rename_all,
+                    r#"`rename_all` must be one of "lowercase", "PascalCase", "camelCase", "snake_case", "SCREAMING_SNAKE_CASE" or "kebab-case"."#,
+                )
+            })
+        })
+        .transpose()?
+        .unwrap_or(RenameRule::None);
+
+    // 重複チェックのため値を文字列としても保持する
+    let mut variants = Vec::<(Ident, TokenStream, String)>::new();
+    let mut other = Option::<Ident>::None;
+
+    for variant in data_enum.variants.iter() {
+        let receiver = VariantReceiver::from_variant(variant)?;
+
+        if receiver.other {
+            if other.is_some() {
+                return Err(syn::Error::new_spanned(
+                    variant,
+                    "`other` can be set to at most one variant.",
+                ));
+            }
+            if !matches!(&variant.fields, syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1)
+            {
+                return Err(syn::Error::new_spanned(
+                    variant,
+                    "`other` variant must have only one unnamed field.",
+                ));
+            }
+            other = Some(variant.ident.clone());
+            continue;
+        }
+
+        if !variant.fields.is_empty() {
+            return Err(syn::Error::new_spanned(
+                variant,
+                "`DeriveNewType` for enum can only use for variants thats have no field except `other` variant.",
+            ));
         }
+
+        let (value, key) = if repr.is_some() {
+            if let Some(value) = receiver.value {
+                return Err(syn::Error::new_spanned(
+                    value,
+                    "`value` can not be used for enum with integer repr. Use the discriminant instead.",
+                ));
+            }
+            let discriminant = variant
+                .discriminant
+                .as_ref()
+                .map(|(_, expr)| expr)
+                .ok_or_else(|| {
+                    syn::Error::new_spanned(
+                        variant,
+                        "enum with integer repr must have explicit discriminant for each variant.",
+                    )
+                })?;
+            let key = parse_integer_literal(discriminant)?.to_string();
+            (quote! {#discriminant}, key)
+        } else {
+            let value = receiver.value.unwrap_or_else(|| {
+                LitStr::new(
+                    &rename_rule.apply_to_variant(variant.ident.to_string()),
+                    variant.ident.span(),
+                )
+            });
+            (quote! {#value}, value.value())
+        };
+
+        if variants.iter().any(|(_, _, other)| *other == key) {
+            return Err(syn::Error::new_spanned(
+                variant,
+                format!("value `{key}` is duplicated."),
+            ));
+        }
+
+        variants.push((variant.ident.clone(), value, key));
+    }
+
+    Ok(EnumVariants {
+        repr,
+        variants: variants
+            .into_iter()
+            .map(|(ident, value, _)| (ident, value))
+            .collect(),
+        other,
     })
 }
 
-/// transparentの場合に型を取得．フィールドが無名かつ一つだけであるかどうかチェック
-fn get_and_check_transparent_type(input: &DeriveInput) -> syn::Result<Ident> {
-    if let syn::Data::Struct(data_struct) = &input.data {
-        if let syn::Fields::Unnamed(unnamed_fields) = &data_struct.fields {
-            if unnamed_fields.unnamed.len() == 1 {
-                let base_type_ty = &(unnamed_fields.unnamed.first().unwrap().ty);
+/// `#[repr(i16)]`などの整数のrepr型を取得
+fn get_integer_repr(input: &DeriveInput) -> syn::Result<Option<Ident>> {
+    const INTEGER_TYPES: [&str; 8] = ["i8", "i16", "i32", "i64", "u8", "u16", "u32", "u64"];
 
-                return Ok(parse_quote!(#base_type_ty));
+    let mut repr = None;
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("repr"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if let Some(ident) = meta.path.get_ident() {
+                if INTEGER_TYPES.iter().any(|ty| ident == ty) {
+                    repr = Some(ident.clone());
+                }
             }
+            Ok(())
+        })?;
+    }
+
+    Ok(repr)
+}
+
+/// 判別子が整数リテラル(負数を含む)であるかチェックし，その値を取得
+fn parse_integer_literal(expr: &Expr) -> syn::Result<i128> {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(lit_int),
+            ..
+        }) => lit_int.base10_parse::<i128>(),
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr,
+            ..
+        }) => parse_integer_literal(expr).map(|n| -n),
+        _ => Err(syn::Error::new_spanned(
+            expr,
+            "discriminant must be an integer literal.",
+        )),
+    }
+}
+
+/// `column_type = "String(Some(64))"`などをColumnTypeの式とする．`Custom("name")`はColumnType::customで構築する
+fn column_type_expr(column_type: &LitStr) -> syn::Result<TokenStream> {
+    match column_type.parse::<Expr>()? {
+        Expr::Call(ExprCall { func, args, .. }) if matches!(&*func, Expr::Path(ExprPath { path, .. }) if path.is_ident("Custom")) => {
+            Ok(quote! {__sea_orm_newtype::sea_query::ColumnType::custom(#args)})
         }
+        expr => Ok(quote! {__sea_orm_newtype::sea_query::ColumnType::#expr}),
     }
+}
+
+/// `array_type = "Int"`などをArrayTypeの式とする
+fn array_type_expr(array_type: &LitStr) -> syn::Result<TokenStream> {
+    let expr = array_type.parse::<Expr>()?;
+    Ok(quote! {__sea_orm_newtype::sea_query::ArrayType::#expr})
+}
 
-    Err(syn::Error::new(
-        Span::call_site(),
-        r#"`sea_orm_newtype(transparent)` can only use for tuple struct thats have only one field."#,
-    ))
+/// 変換の式`convert_block`(Result<Self, `error_type`>)の結果を`validate`で検証する式．検証のエラーは`map_err`で変換する
+fn validated(
+    convert_block: TokenStream,
+    error_type: TokenStream,
+    validate: Option<&Path>,
+    map_err: TokenStream,
+) -> TokenStream {
+    match validate {
+        Some(validate) => quote! {
+            let value: ::core::result::Result<Self, #error_type> = { #convert_block };
+            let value = value?;
+            #validate(&value).map_err(#map_err)?;
+            ::core::result::Result::Ok(value)
+        },
+        None => convert_block,
+    }
 }
 
-/// impl From<NewType> for Value
+/// トークン列の位置を全て`span`に置き換える
+fn respan(tokens: TokenStream, span: Span) -> TokenStream {
+    tokens
+        .into_iter()
+        .map(|token| match token {
+            TokenTree::Group(group) => {
+                let mut new_group = Group::new(group.delimiter(), respan(group.stream(), span));
+                new_group.set_span(span);
+                TokenTree::Group(new_group)
+            }
+            mut token => {
+                token.set_span(span);
+                token
+            }
+        })
+        .collect()
+}
+
+/// 型がPhantomDataかどうか
+fn is_phantom_data(ty: &Type) -> bool {
+    match ty {
+        Type::Path(type_path) => type_path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "PhantomData"),
+        _ => false,
+    }
+}
+
+/// impl From<NewType> for Value．`normalizers`は経由する型の値に順に適用する正規化の関数とnormalizeの位置
 fn from_newtype_for_value(
     new_type_name: &Ident,
     convert_type: &ConvertType,
-    generics: &Generics,
+    bounds: &Bounds,
+    normalizers: Option<(&[TokenStream], Span)>,
 ) -> TokenStream {
+    let generics = bounds.with(quote! {::core::convert::Into<__sea_orm_newtype::Value>});
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
+    // 経由する型の値`base`を(正規化して)Valueに変換する式
+    let base_into_value = |base: TokenStream| match normalizers {
+        Some((functions, span)) => {
+            let normalized = functions
+                .iter()
+                .fold(base, |value, function| quote! { #function(#value) });
+            // 経由する型が合わない場合のエラーはnormalizeの位置に表示される
+            let normalized = respan(normalized, span);
+            quote! { ::core::convert::Into::<__sea_orm_newtype::Value>::into(#normalized) }
+        }
+        None => quote! { ::core::convert::Into::<__sea_orm_newtype::Value>::into(#base) },
+    };
+
     let from_block = match convert_type {
         FromInto(base_type_name) | TryFromInto(base_type_name) => {
-            quote! { Into::<#base_type_name>::into(value).into() }
+            base_into_value(quote! { ::core::convert::Into::<#base_type_name>::into(value) })
         }
-        Transparent(_) => {
-            quote! { value.0.into() }
+        Transparent(field) => base_into_value(field.access(quote! {value})),
+        With(module, _) => base_into_value(quote! { #module::to_value(&value) }),
+        ViaStr(_) => base_into_value(quote! { ::std::string::ToString::to_string(&value) }),
+        Json(_, _) => {
+            // シリアライズの失敗をNULLとして書き出さないようにパニックする
+            let message = format!("failed to serialize `{new_type_name}` into JSON");
+            quote! {
+                __sea_orm_newtype::Value::Json(::core::option::Option::Some(::std::boxed::Box::new(
+                    __sea_orm_newtype::serde_json::to_value(&value).expect(#message)
+                )))
+            }
         }
+        Enum(_, variants) => variants.encode(new_type_name, quote! {value}),
     };
 
     quote! {
-        impl #impl_generics From<#new_type_name #ty_generics> for ::sea_orm_newtype::Value #where_clause {
+        impl #impl_generics ::core::convert::From<#new_type_name #ty_generics> for __sea_orm_newtype::Value #where_clause {
             fn from(value: #new_type_name #ty_generics) -> Self {
                 #from_block
             }
@@ -158,115 +1202,512 @@ fn from_newtype_for_value(
     }
 }
 
+/// impl NewType { fn __try_get_from_base }．TryGetableや配列の読み取りで用いる(skip(try_getable)の場合も生成する)
+fn try_get_from_base_for_newtype(
+    new_type_name: &Ident,
+    convert_type: &ConvertType,
+    bounds: &Bounds,
+    error_handling: &ErrorHandling,
+    convert_span: Span,
+    redact: bool,
+    validate: Option<&Path>,
+) -> TokenStream {
+    let generics = bounds.with(quote! {__sea_orm_newtype::TryGetable + ::core::fmt::Debug});
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    // アサーションを追加しておく(エラーはerror_kindに応じたトレイトを実装する)
+    let error_type = convert_type.error_type(new_type_name, &generics);
+    let assertion_error_bound = error_type.clone().and_then(|error_type| {
+        let assertion_fn = match convert_type {
+            ViaStr(_) => format_ident!("__Assertion{}FromStr", new_type_name),
+            _ => format_ident!("__Assertion{}TryFrom", new_type_name),
+        };
+        error_handling.assertion(assertion_fn, error_type, convert_span)
+    });
+
+    let base_type_name = convert_type.base_type();
+    let convert_block = try_get_convert(
+        new_type_name,
+        convert_type,
+        error_handling,
+        error_type.as_ref(),
+        convert_span,
+        redact,
+        quote! {R},
+    );
+    let convert_block = validated(
+        convert_block,
+        quote! {__sea_orm_newtype::sea_orm::TryGetError},
+        validate,
+        ErrorHandling::Kind(ErrorKind::Std).try_get_err(&quote! {R}, redact, None),
+    );
+
+    quote! {
+        // アサーション
+        #assertion_error_bound
+
+        impl #impl_generics #new_type_name #ty_generics #where_clause {
+            // 読み取った経由する型の値から変換する(Rはエラーの際に値を再度読み取るための型)
+            #[allow(unused_variables, dead_code, clippy::redundant_closure)]
+            fn __try_get_from_base<R, I>(
+                value: #base_type_name,
+                res: &__sea_orm_newtype::sea_orm::QueryResult,
+                index: I,
+            ) -> ::core::result::Result<Self, __sea_orm_newtype::sea_orm::TryGetError>
+            where
+                R: __sea_orm_newtype::TryGetable + ::core::fmt::Debug,
+                I: __sea_orm_newtype::sea_orm::ColIdx,
+            {
+                #convert_block
+            }
+        }
+    }
+}
+
 /// impl TryGetable for NewType
 fn try_getable_for_newtype(
     new_type_name: &Ident,
     convert_type: &ConvertType,
-    generics: &Generics,
+    bounds: &Bounds,
 ) -> TokenStream {
+    let generics = bounds.with(quote! {__sea_orm_newtype::TryGetable + ::core::fmt::Debug});
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
-    let mut assertion_error_bound = Option::<TokenStream>::None;
+    let base_type_name = convert_type.base_type();
 
-    let try_get_block = match convert_type {
-        FromInto(base_type_name) => {
-            quote! { Ok(Into::<Self>::into(res.try_get_by::<#base_type_name, I>(index)?)) }
+    quote! {
+        impl #impl_generics __sea_orm_newtype::TryGetable for #new_type_name #ty_generics #where_clause
+        {
+            fn try_get_by<I: __sea_orm_newtype::sea_orm::ColIdx>(
+                res: &__sea_orm_newtype::sea_orm::QueryResult,
+                index: I,
+            ) -> ::core::result::Result<Self, __sea_orm_newtype::sea_orm::TryGetError> {
+                let value = <#base_type_name as __sea_orm_newtype::TryGetable>::try_get_by(res, index)?;
+                Self::__try_get_from_base::<#base_type_name, I>(value, res, index)
+            }
         }
-        TryFromInto(base_type_name) => {
-            // アサーションを追加しておく(エラーはstd::error::Errorを実装する)
-            assertion_error_bound = {
-                let assertion_temp_type = format_ident!("__Assertion{}TryFrom", new_type_name);
-
-                Some(quote! {
-                    #[allow(non_camel_case_types)]
-                    struct #assertion_temp_type where
-                    <#new_type_name as TryFrom<#base_type_name>>::Error: ::std::error::Error;
-                })
-            };
+    }
+}
 
-            quote! {
-                Ok(
-                    TryInto::<Self>::try_into(res.try_get_by::<#base_type_name, I>(index)?)
-                        .map_err(|e| ::sea_orm_newtype::sea_orm::DbErr::Custom(e.to_string()))?,
-                )
-            }
+/// 読み取った経由する型の値`value`をNewTypeに変換する式(Result<Self, TryGetError>)．`read_type`はエラーの際に値を再度読み取るための型
+fn try_get_convert(
+    new_type_name: &Ident,
+    convert_type: &ConvertType,
+    error_handling: &ErrorHandling,
+    error_type: Option<&TokenStream>,
+    convert_span: Span,
+    redact: bool,
+    read_type: TokenStream,
+) -> TokenStream {
+    match convert_type {
+        FromInto(_) => {
+            quote! { ::core::result::Result::Ok(::core::convert::Into::<Self>::into(value)) }
+        }
+        TryFromInto(_) => {
+            let map_err = respan(
+                error_handling.try_get_err(&read_type, redact, error_type),
+                convert_span,
+            );
+            quote! { ::core::result::Result::Ok(::core::convert::TryInto::<Self>::try_into(value).map_err(#map_err)?) }
         }
-        Transparent(base_type_name) => {
-            quote! { Ok(#new_type_name (res.try_get_by::<#base_type_name, I>(index)?)) }
+        Transparent(field) => {
+            let construct = field.construct(new_type_name, quote! {value});
+            quote! { ::core::result::Result::Ok(#construct) }
         }
+        With(module, _) => {
+            let map_err = respan(
+                error_handling.try_get_err(&read_type, redact, error_type),
+                convert_span,
+            );
+            quote! { ::core::result::Result::Ok(#module::from_value(value).map_err(#map_err)?) }
+        }
+        ViaStr(_) => {
+            let map_err = respan(
+                error_handling.try_get_err(&read_type, redact, error_type),
+                convert_span,
+            );
+            quote! { ::core::result::Result::Ok(<Self as ::std::str::FromStr>::from_str(&value).map_err(#map_err)?) }
+        }
+        Json(..) => {
+            // serde_json::Errorはstd::error::Errorを実装する
+            let map_err = ErrorHandling::Kind(ErrorKind::Std).try_get_err(&read_type, redact, None);
+            quote! { ::core::result::Result::Ok(__sea_orm_newtype::serde_json::from_value(value).map_err(#map_err)?) }
+        }
+        Enum(_, variants) => variants.decode(
+            new_type_name,
+            quote! {value},
+            quote! {
+                ::core::result::Result::Err(::core::convert::Into::into(
+                    __sea_orm_newtype::ConvertError::__try_get_err::<Self, #read_type, I>(
+                        res, index, #redact, "unknown value",
+                    ),
+                ))
+            },
+        ),
+    }
+}
+
+/// impl NotU8 for NewType, impl TryGetableArray for NewType (postgres-array)．skip(try_getable)の場合はNotU8のみ
+fn postgres_array_for_newtype(
+    new_type_name: &Ident,
+    convert_type: &ConvertType,
+    bounds: &Bounds,
+    skip_try_getable: bool,
+) -> TokenStream {
+    let generics = bounds.with(quote! {__sea_orm_newtype::TryGetable + ::core::fmt::Debug});
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let not_u8 = quote! {
+        impl #impl_generics __sea_orm_newtype::sea_query::with_array::NotU8 for #new_type_name #ty_generics #where_clause {}
     };
+    if skip_try_getable {
+        return not_u8;
+    }
+
+    let base_type_name = convert_type.base_type();
+
+    // 経由する型が配列として読み取れない場合は実装されないように，高階のトレイト境界とする
+    let mut array_generics = generics.clone();
+    array_generics
+        .make_where_clause()
+        .predicates
+        .push(parse_quote! {
+            for<'__a> ::std::vec::Vec<#base_type_name>: __sea_orm_newtype::TryGetable
+        });
+    let (_, _, array_where_clause) = array_generics.split_for_impl();
 
     quote! {
-        // アサーション
-        #assertion_error_bound
+        #not_u8
 
-        impl #impl_generics ::sea_orm_newtype::TryGetable for #new_type_name #ty_generics #where_clause
+        impl #impl_generics __sea_orm_newtype::sea_orm::TryGetableArray for #new_type_name #ty_generics #array_where_clause
         {
-            fn try_get_by<I: ::sea_orm_newtype::sea_orm::ColIdx>(
-                res: &::sea_orm_newtype::sea_orm::QueryResult,
+            fn try_get_by<I: __sea_orm_newtype::sea_orm::ColIdx>(
+                res: &__sea_orm_newtype::sea_orm::QueryResult,
                 index: I,
-            ) -> Result<Self, ::sea_orm_newtype::sea_orm::TryGetError> {
-                #try_get_block
+            ) -> ::core::result::Result<::std::vec::Vec<Self>, __sea_orm_newtype::sea_orm::TryGetError> {
+                let values = <::std::vec::Vec<#base_type_name> as __sea_orm_newtype::TryGetable>::try_get_by(res, index)?;
+                ::core::iter::Iterator::collect(::core::iter::Iterator::map(
+                    ::core::iter::IntoIterator::into_iter(values),
+                    |value| Self::__try_get_from_base::<::std::vec::Vec<#base_type_name>, I>(value, res, index),
+                ))
             }
         }
     }
 }
 
+/// Vec<NewType>をJSONの配列もしくは区切り文字で連結した文字列として保存する型と，その型に対するFrom, TryGetable, ValueType, Nullableの実装
+fn array_fallback_for_newtype(
+    input: &DeriveInput,
+    convert_type: &ConvertType,
+    array_name: &Ident,
+    encoding: &ArrayEncoding,
+    bounds: &Bounds,
+    redact: bool,
+    fallback_span: Span,
+) -> (TokenStream, TokenStream) {
+    let new_type_name = &input.ident;
+    let vis = &input.vis;
+    let generics = &input.generics;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    // 値の変換を行うimplのトレイト境界
+    let convert_generics = bounds.with_predicates(|ty| {
+        vec![parse_quote! {
+            #ty: ::core::convert::Into<__sea_orm_newtype::Value>
+                + __sea_orm_newtype::TryGetable
+                + ::core::fmt::Debug
+                + __sea_orm_newtype::ValueType
+                + __sea_orm_newtype::__ArrayElement
+        }]
+    });
+    let (convert_impl_generics, _, convert_where_clause) = convert_generics.split_for_impl();
+
+    let base_type_name = convert_type.base_type();
+    let element = quote! {#new_type_name #ty_generics};
+
+    // 要素として保存できない経由する型のエラーはarray_fallbackの位置に表示される
+    let encode_json_array = respan(
+        quote! {__sea_orm_newtype::__encode_json_array::<#base_type_name>},
+        fallback_span,
+    );
+    let encode_delimited = respan(
+        quote! {__sea_orm_newtype::__encode_delimited::<#base_type_name>},
+        fallback_span,
+    );
+
+    // (読み取る型, 要素のValueの列`values`から変換する式, 読み取った値`raw`から要素のValueの列への変換式, ArrayType, ColumnType, 説明)
+    let (read_type, encode, decode, array_type, column_type, description) = match encoding {
+        ArrayEncoding::Json => (
+            quote! {__sea_orm_newtype::sea_orm::prelude::Json},
+            quote! {#encode_json_array(values)},
+            quote! {__sea_orm_newtype::__decode_json_array(raw, array_type)},
+            quote! {Json},
+            quote! {Json},
+            "a JSON array".to_owned(),
+        ),
+        ArrayEncoding::Delimited(delimiter) => (
+            quote! {::std::string::String},
+            quote! {#encode_delimited(values, #delimiter)},
+            quote! {__sea_orm_newtype::__decode_delimited(&raw, #delimiter, array_type)},
+            quote! {String},
+            quote! {Text},
+            format!("a string joined by `{delimiter}`"),
+        ),
+    };
+
+    let doc = format!("`Vec<{new_type_name}>` stored as {description}.");
+
+    let array_struct = quote! {
+        #[doc = #doc]
+        #[derive(::core::clone::Clone, ::core::fmt::Debug, ::core::cmp::PartialEq)]
+        #vis struct #array_name #generics (pub ::std::vec::Vec<#element>) #where_clause;
+    };
+
+    let impls = quote! {
+        impl #impl_generics ::core::ops::Deref for #array_name #ty_generics #where_clause {
+            type Target = ::std::vec::Vec<#element>;
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        impl #impl_generics ::core::ops::DerefMut for #array_name #ty_generics #where_clause {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.0
+            }
+        }
+
+        impl #impl_generics ::core::convert::From<::std::vec::Vec<#element>> for #array_name #ty_generics #where_clause {
+            fn from(value: ::std::vec::Vec<#element>) -> Self {
+                Self(value)
+            }
+        }
+
+        impl #impl_generics ::core::convert::From<#array_name #ty_generics> for ::std::vec::Vec<#element> #where_clause {
+            fn from(value: #array_name #ty_generics) -> Self {
+                value.0
+            }
+        }
+
+        impl #convert_impl_generics ::core::convert::From<#array_name #ty_generics> for __sea_orm_newtype::Value #convert_where_clause {
+            fn from(value: #array_name #ty_generics) -> Self {
+                let values = ::core::iter::Iterator::map(
+                    ::core::iter::IntoIterator::into_iter(value.0),
+                    ::core::convert::Into::<__sea_orm_newtype::Value>::into,
+                );
+                #encode
+            }
+        }
+
+        impl #convert_impl_generics __sea_orm_newtype::TryGetable for #array_name #ty_generics #convert_where_clause
+        {
+            fn try_get_by<I: __sea_orm_newtype::sea_orm::ColIdx>(
+                res: &__sea_orm_newtype::sea_orm::QueryResult,
+                index: I,
+            ) -> ::core::result::Result<Self, __sea_orm_newtype::sea_orm::TryGetError> {
+                let raw = <#read_type as __sea_orm_newtype::TryGetable>::try_get_by(res, index)?;
+                let array_type = <#base_type_name as __sea_orm_newtype::ValueType>::array_type();
+                let values = (#decode).map_err(|e| {
+                    __sea_orm_newtype::ConvertError::__try_get_err::<Self, #read_type, I>(res, index, #redact, e)
+                })?;
+                ::core::iter::Iterator::collect::<::core::result::Result<_, _>>(::core::iter::Iterator::map(
+                    ::core::iter::IntoIterator::into_iter(values),
+                    |value| {
+                        let value = <#base_type_name as __sea_orm_newtype::ValueType>::try_from(value).map_err(|e| {
+                            __sea_orm_newtype::ConvertError::__try_get_err::<Self, #read_type, I>(res, index, #redact, e)
+                        })?;
+                        <#element>::__try_get_from_base::<#read_type, I>(value, res, index)
+                    },
+                ))
+                .map(Self)
+            }
+        }
+
+        impl #convert_impl_generics __sea_orm_newtype::ValueType for #array_name #ty_generics #convert_where_clause {
+            fn try_from(
+                v: __sea_orm_newtype::Value,
+            ) -> ::core::result::Result<Self, __sea_orm_newtype::sea_query::ValueTypeErr> {
+                let raw = <#read_type as __sea_orm_newtype::ValueType>::try_from(v)?;
+                let array_type = <#base_type_name as __sea_orm_newtype::ValueType>::array_type();
+                let values = (#decode).map_err(|_| __sea_orm_newtype::sea_query::ValueTypeErr)?;
+                ::core::iter::Iterator::collect::<::core::result::Result<_, _>>(::core::iter::Iterator::map(
+                    ::core::iter::IntoIterator::into_iter(values),
+                    <#element as __sea_orm_newtype::ValueType>::try_from,
+                ))
+                .map(Self)
+            }
+            fn type_name() -> ::std::string::String {
+                ::std::string::String::from(::core::stringify!(#array_name))
+            }
+            fn array_type() -> __sea_orm_newtype::sea_query::ArrayType {
+                __sea_orm_newtype::sea_query::ArrayType::#array_type
+            }
+            fn column_type() -> __sea_orm_newtype::sea_query::ColumnType {
+                __sea_orm_newtype::sea_query::ColumnType::#column_type
+            }
+        }
+
+        impl #convert_impl_generics __sea_orm_newtype::Nullable for #array_name #ty_generics #convert_where_clause
+        {
+            fn null() -> __sea_orm_newtype::Value {
+                <#read_type as __sea_orm_newtype::Nullable>::null()
+            }
+        }
+    };
+
+    let impl_into_active_value = into_active_value_for_newtype(array_name, &convert_generics);
+
+    (
+        array_struct,
+        quote! {
+            #impls
+
+            #impl_into_active_value
+        },
+    )
+}
+
 /// impl ValueType for NewType
 fn value_type_for_newtype(
     new_type_name: &Ident,
     convert_type: &ConvertType,
-    generics: &Generics,
-    use_type_name: bool,
+    bounds: &Bounds,
+    type_name: Option<&Override<LitStr>>,
+    column_type: Option<TokenStream>,
+    array_type: Option<TokenStream>,
+    validate: Option<&Path>,
 ) -> TokenStream {
+    let generics = bounds.with(quote! {__sea_orm_newtype::ValueType});
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
     let try_from_block = match convert_type {
         FromInto(base_type_name) => {
             quote! {
-                Ok(
-                    Into::<Self>::into(<#base_type_name as ::sea_orm_newtype::ValueType>::try_from(v)?)
+                ::core::result::Result::Ok(
+                    ::core::convert::Into::<Self>::into(<#base_type_name as __sea_orm_newtype::ValueType>::try_from(v)?)
                 )
             }
         }
         TryFromInto(base_type_name) => {
             quote! {
-                TryInto::<Self>::try_into(<#base_type_name as ::sea_orm_newtype::ValueType>::try_from(v)?)
-                .map_err(|_| ::sea_orm_newtype::sea_query::ValueTypeErr)
+                ::core::convert::TryInto::<Self>::try_into(<#base_type_name as __sea_orm_newtype::ValueType>::try_from(v)?)
+                .map_err(|_| __sea_orm_newtype::sea_query::ValueTypeErr)
+            }
+        }
+        Transparent(field) => {
+            let base_type_name = &field.ty;
+            let construct = field.construct(
+                new_type_name,
+                quote! {<#base_type_name as __sea_orm_newtype::ValueType>::try_from(v)?},
+            );
+            quote! { ::core::result::Result::Ok(#construct) }
+        }
+        With(module, base_type_name) => {
+            quote! {
+                #module::from_value(<#base_type_name as __sea_orm_newtype::ValueType>::try_from(v)?)
+                .map_err(|_| __sea_orm_newtype::sea_query::ValueTypeErr)
             }
         }
-        Transparent(base_type_name) => {
+        ViaStr(base_type_name) => {
             quote! {
-                Ok(#new_type_name(<#base_type_name as ::sea_orm_newtype::ValueType>::try_from(v)?))
+                <Self as ::std::str::FromStr>::from_str(&<#base_type_name as __sea_orm_newtype::ValueType>::try_from(v)?)
+                .map_err(|_| __sea_orm_newtype::sea_query::ValueTypeErr)
+            }
+        }
+        Json(base_type_name, _) => {
+            quote! {
+                __sea_orm_newtype::serde_json::from_value(<#base_type_name as __sea_orm_newtype::ValueType>::try_from(v)?)
+                .map_err(|_| __sea_orm_newtype::sea_query::ValueTypeErr)
+            }
+        }
+        Enum(base_type_name, variants) => {
+            let from_value = variants.decode(
+                new_type_name,
+                quote! {value},
+                quote! {::core::result::Result::Err(__sea_orm_newtype::sea_query::ValueTypeErr)},
+            );
+            quote! {
+                let value = <#base_type_name as __sea_orm_newtype::ValueType>::try_from(v)?;
+                #from_value
             }
         }
     };
+    let try_from_block = validated(
+        try_from_block,
+        quote! {__sea_orm_newtype::sea_query::ValueTypeErr},
+        validate,
+        quote! {|_| __sea_orm_newtype::sea_query::ValueTypeErr},
+    );
 
-    let type_name_block = if use_type_name {
-        quote! {stringify!(#new_type_name #ty_generics).to_owned()}
-    } else {
-        let base_type_name = convert_type.ident();
-        quote! {<#base_type_name as ::sea_orm_newtype::ValueType>::type_name()}
+    let type_name_block = match type_name {
+        Some(Override::Explicit(type_name)) => quote! {::std::string::String::from(#type_name)},
+        Some(Override::Inherit) => {
+            // ジェネリクスの型引数は具体的な型の名前とする(ライフタイムは省略する)
+            let args = generics
+                .params
+                .iter()
+                .filter_map(|param| match param {
+                    GenericParam::Type(TypeParam { ident, .. }) => {
+                        Some(quote! {__sea_orm_newtype::__short_type_name::<#ident>()})
+                    }
+                    GenericParam::Const(ConstParam { ident, .. }) => {
+                        Some(quote! {::std::string::ToString::to_string(&#ident)})
+                    }
+                    GenericParam::Lifetime(_) => None,
+                })
+                .collect::<Vec<_>>();
+            if args.is_empty() {
+                quote! {::std::string::String::from(::core::stringify!(#new_type_name))}
+            } else {
+                quote! {
+                    ::std::format!(
+                        "{}<{}>",
+                        ::core::stringify!(#new_type_name),
+                        <[::std::string::String]>::join(&[#(#args),*], ", "),
+                    )
+                }
+            }
+        }
+        None => {
+            let base_type_name = convert_type.base_type();
+            quote! {<#base_type_name as __sea_orm_newtype::ValueType>::type_name()}
+        }
     };
 
-    {
-        let base_type_name = convert_type.ident();
-        quote! {
-            impl #impl_generics ::sea_orm_newtype::ValueType for #new_type_name #ty_generics #where_clause {
-                fn try_from(
-                    v: ::sea_orm_newtype::Value,
-                ) -> Result<Self, ::sea_orm_newtype::sea_query::ValueTypeErr> {
-                    #try_from_block
-                }
-                fn type_name() -> String {
-                    #type_name_block
-                }
-                fn array_type() -> ::sea_orm_newtype::sea_query::ArrayType {
-                    <#base_type_name as ::sea_orm_newtype::ValueType>::array_type()
-                }
-                fn column_type() -> ::sea_orm_newtype::sea_query::ColumnType {
-                    <#base_type_name as ::sea_orm_newtype::ValueType>::column_type()
-                }
+    let column_type_block = match (column_type, convert_type) {
+        (Some(column_type), _) => column_type,
+        (None, Json(_, JsonOptions { binary: true })) => {
+            quote! {__sea_orm_newtype::sea_query::ColumnType::JsonBinary}
+        }
+        (None, Json(_, JsonOptions { binary: false })) => {
+            quote! {__sea_orm_newtype::sea_query::ColumnType::Json}
+        }
+        (None, _) => {
+            let base_type_name = convert_type.base_type();
+            quote! {<#base_type_name as __sea_orm_newtype::ValueType>::column_type()}
+        }
+    };
+
+    let array_type_block = array_type.unwrap_or_else(|| {
+        let base_type_name = convert_type.base_type();
+        quote! {<#base_type_name as __sea_orm_newtype::ValueType>::array_type()}
+    });
+
+    quote! {
+        impl #impl_generics __sea_orm_newtype::ValueType for #new_type_name #ty_generics #where_clause {
+            fn try_from(
+                v: __sea_orm_newtype::Value,
+            ) -> ::core::result::Result<Self, __sea_orm_newtype::sea_query::ValueTypeErr> {
+                #try_from_block
+            }
+            fn type_name() -> ::std::string::String {
+                #type_name_block
+            }
+            fn array_type() -> __sea_orm_newtype::sea_query::ArrayType {
+                #array_type_block
+            }
+            fn column_type() -> __sea_orm_newtype::sea_query::ColumnType {
+                #column_type_block
             }
         }
     }
@@ -276,17 +1717,32 @@ fn value_type_for_newtype(
 fn nullable_for_newtype(
     new_type_name: &Ident,
     convert_type: &ConvertType,
-    generics: &Generics,
+    bounds: &Bounds,
 ) -> TokenStream {
+    let generics = bounds.with(quote! {__sea_orm_newtype::Nullable});
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
-    let base_type_name = convert_type.ident();
+    let base_type_name = convert_type.base_type();
 
     quote! {
-        impl #impl_generics ::sea_orm_newtype::Nullable for #new_type_name #ty_generics #where_clause
+        impl #impl_generics __sea_orm_newtype::Nullable for #new_type_name #ty_generics #where_clause
         {
-            fn null() -> ::sea_orm_newtype::Value {
-                <#base_type_name as ::sea_orm_newtype::Nullable>::null()
+            fn null() -> __sea_orm_newtype::Value {
+                <#base_type_name as __sea_orm_newtype::Nullable>::null()
+            }
+        }
+    }
+}
+
+/// impl IntoActiveValue<NewType> for NewType (Option<NewType>はsea-ormのimplによる)．`generics`はInto<Value>を実装するための境界を課したもの
+fn into_active_value_for_newtype(new_type_name: &Ident, generics: &Generics) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics __sea_orm_newtype::sea_orm::IntoActiveValue<#new_type_name #ty_generics> for #new_type_name #ty_generics #where_clause
+        {
+            fn into_active_value(self) -> __sea_orm_newtype::sea_orm::ActiveValue<Self> {
+                __sea_orm_newtype::sea_orm::ActiveValue::Set(self)
             }
         }
     }
@@ -296,39 +1752,180 @@ fn nullable_for_newtype(
 fn try_from_u64_for_newtype(
     new_type_name: &Ident,
     convert_type: &ConvertType,
-    generics: &Generics,
+    bounds: &Bounds,
+    error_handling: &ErrorHandling,
+    validate: Option<&Path>,
+    convert_span: Span,
+    primary_key_span: Span,
 ) -> TokenStream {
+    let generics = bounds.with(quote! {__sea_orm_newtype::TryFromU64});
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
+    // 経由する型がTryFromU64を実装しない場合のエラーはアサーションとしてprimary_keyの位置に表示される
+    // (読み取りでも同じ関数を同じ位置で参照するので，エラーは一つにまとめられる)
+    let base_type_name = convert_type.base_type();
+    let base_try_from_u64 = respan(
+        quote! {__sea_orm_newtype::__try_from_u64::<#base_type_name>},
+        primary_key_span,
+    );
+    let assertion_try_from_u64 = generics.params.is_empty().then(|| {
+        let assertion_fn = format_ident!("__Assertion{}TryFromU64", new_type_name);
+        respan(
+            quote! {
+                #[allow(non_snake_case, dead_code)]
+                fn #assertion_fn() {
+                    let _ = #base_try_from_u64;
+                }
+            },
+            primary_key_span,
+        )
+    });
+    let base_try_from_u64 = quote! {(#base_try_from_u64)(n)?};
+
+    // 変換時のエラーの位置は読み取りと同じくconvertの位置とする
+    let error_type = convert_type.error_type(new_type_name, &generics);
+    let map_err = respan(
+        error_handling.try_into_err(base_type_name, error_type.as_ref()),
+        convert_span,
+    );
+
     let try_from_u64_block = match convert_type {
-        FromInto(base_type_name) => {
+        FromInto(_) => {
+            quote! { ::core::result::Result::Ok(::core::convert::Into::<Self>::into(#base_try_from_u64)) }
+        }
+        TryFromInto(_) => {
             quote! {
-                Ok(
-                    Into::<Self>::into(<#base_type_name as ::sea_orm_newtype::TryFromU64>::try_from_u64(n)?)
-                )
+                ::core::convert::TryInto::<Self>::try_into(#base_try_from_u64)
+                    .map_err(#map_err)
             }
         }
-        TryFromInto(base_type_name) => {
+        Transparent(field) => {
+            let construct = field.construct(new_type_name, base_try_from_u64.clone());
+            quote! { ::core::result::Result::Ok(#construct) }
+        }
+        With(module, _) => {
             quote! {
-                TryInto::<Self>::try_into(<#base_type_name as ::sea_orm_newtype::TryFromU64>::try_from_u64(n)?)
-                    .map_err(|e| ::sea_orm_newtype::sea_orm::DbErr::Custom(e.to_string()))
+                #module::from_value(#base_try_from_u64)
+                    .map_err(#map_err)
             }
         }
-        Transparent(base_type_name) => {
+        ViaStr(_) => {
             quote! {
-                Ok(
-                    #new_type_name(<#base_type_name as ::sea_orm_newtype::TryFromU64>::try_from_u64(n)?)
-                )
+                <Self as ::std::str::FromStr>::from_str(&#base_try_from_u64)
+                    .map_err(#map_err)
+            }
+        }
+        Json(..) => {
+            quote! {
+                __sea_orm_newtype::serde_json::from_value(#base_try_from_u64)
+                    .map_err(|e| __sea_orm_newtype::sea_orm::DbErr::Json(::std::string::ToString::to_string(&e)))
+            }
+        }
+        Enum(_, variants) => {
+            let from_value = variants.decode(
+                new_type_name,
+                quote! {value},
+                quote! {
+                    ::core::result::Result::Err(__sea_orm_newtype::sea_orm::DbErr::Type(::std::format!(
+                        "`{}` is not a valid value for {}",
+                        value,
+                        ::core::stringify!(#new_type_name)
+                    )))
+                },
+            );
+            quote! {
+                let value = #base_try_from_u64;
+                #from_value
             }
         }
     };
+    let try_from_u64_block = validated(
+        try_from_u64_block,
+        quote! {__sea_orm_newtype::sea_orm::DbErr},
+        validate,
+        ErrorHandling::Kind(ErrorKind::Std).try_into_err(base_type_name, None),
+    );
 
     quote! {
-        impl #impl_generics ::sea_orm_newtype::TryFromU64 for #new_type_name #ty_generics #where_clause
+        // アサーション
+        #assertion_try_from_u64
+
+        impl #impl_generics __sea_orm_newtype::TryFromU64 for #new_type_name #ty_generics #where_clause
         {
-            fn try_from_u64(n: u64) -> Result<Self, ::sea_orm_newtype::sea_orm::DbErr> {
+            #[allow(clippy::redundant_closure)]
+            fn try_from_u64(n: u64) -> ::core::result::Result<Self, __sea_orm_newtype::sea_orm::DbErr> {
                 #try_from_u64_block
             }
         }
     }
 }
+
+/// impl From<A> (or TryFrom<A>) for NewType, impl From<NewType> for A
+fn conversions_for_newtype(
+    input: &DeriveInput,
+    convert_type: &ConvertType,
+    conversions: &DeriveConversions,
+    span: Span,
+    generics: &Generics,
+) -> syn::Result<TokenStream> {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let new_type_name = &input.ident;
+    let base_field = get_and_check_base_field(input, "derive_conversions", span)?;
+
+    let from_block = match (convert_type, &conversions.validator, &conversions.error) {
+        (FromInto(base_type_name) | TryFromInto(base_type_name), None, None) => {
+            let construct =
+                base_field.construct(new_type_name, quote! {::core::convert::Into::into(value)});
+            quote! {
+                impl #impl_generics ::core::convert::From<#base_type_name> for #new_type_name #ty_generics #where_clause {
+                    fn from(value: #base_type_name) -> Self {
+                        #construct
+                    }
+                }
+            }
+        }
+        (TryFromInto(base_type_name), Some(validator), Some(error)) => {
+            let construct = base_field.construct(new_type_name, quote! {#validator(value)?});
+            quote! {
+                impl #impl_generics ::core::convert::TryFrom<#base_type_name> for #new_type_name #ty_generics #where_clause {
+                    type Error = #error;
+                    fn try_from(value: #base_type_name) -> ::core::result::Result<Self, Self::Error> {
+                        ::core::result::Result::Ok(#construct)
+                    }
+                }
+            }
+        }
+        (TryFromInto(_), Some(_), None) | (TryFromInto(_), None, Some(_)) => {
+            return Err(syn::Error::new(
+                span,
+                r#"`derive_conversions` must be set both validator = "path::fn" and error = "ErrorType"."#,
+            ))
+        }
+        (FromInto(_), _, _) => {
+            return Err(syn::Error::new(
+                span,
+                r#"`derive_conversions` with validator can only use with try_from_into = "BaseType"."#,
+            ))
+        }
+        (Transparent(_) | With(..) | ViaStr(_) | Json(..) | Enum(..), _, _) => {
+            return Err(syn::Error::new(
+                span,
+                r#"`derive_conversions` can only use with from_into = "BaseType" or try_from_into = "BaseType"."#,
+            ))
+        }
+    };
+
+    let base_type_name = convert_type.base_type();
+    let field_value = base_field.access(quote! {value});
+
+    Ok(quote! {
+        #from_block
+
+        impl #impl_generics ::core::convert::From<#new_type_name #ty_generics> for #base_type_name #where_clause {
+            fn from(value: #new_type_name #ty_generics) -> Self {
+                ::core::convert::Into::into(#field_value)
+            }
+        }
+    })
+}
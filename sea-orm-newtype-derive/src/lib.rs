@@ -7,13 +7,69 @@ use syn::{parse_macro_input, DeriveInput};
 /// - `sea_orm::TryGetable for T`
 /// - `sea_query::ValueType for T`
 /// - `sea_query::Nullable for T`
+/// - `sea_orm::IntoActiveValue<T> for T` (`Option<T>` is covered by sea-orm)
 ///
 /// # Attributes
 /// - `from_into = "OrmType"`: NewType is converted into a type that can be used in sea-orm by `From<OrmType>` and `Into<OrmType>` trait.
 /// - `try_from_into = "OrmType"`: NewType is converted into a type that can be used in sea-orm by `TryFrom<OrmType>` and `Into<OrmType>` trait.
-/// - `transparent`: NewType is interpreted as a type of self.0
-/// - `primary_key`: In addition to the defaults, `sea_orm::TryFromU64` is implemented.
-/// - `type_name`: Change the `ValueType::type_name` implementation for using its own name.  
+/// - `with = "module", base_type = "OrmType"`: NewType is converted into `OrmType` by `module::to_value(&NewType) -> OrmType` and `module::from_value(OrmType) -> Result<NewType, E>`.
+/// - `via_str`: NewType is converted into `String` by `FromStr` and `Display` trait.
+/// - `json`: NewType is converted into `serde_json::Value` by `Serialize` and `Deserialize` trait, and its column type is `ColumnType::Json`. Use `json(binary)` for `ColumnType::JsonBinary`. It requires `serde` feature.
+///   `From<NewType> for sea_query::Value` panics if `Serialize` fails, instead of writing `NULL`.
+/// - `transparent`: NewType is interpreted as a type of its base field. The other fields (e.g. `PhantomData`) are filled by `Default::default()`.
+/// - `primary_key`: In addition to the defaults, `sea_orm::TryFromU64` is implemented. The base type must implement `sea_orm::TryFromU64` too, otherwise the error is reported at `primary_key`.
+/// - `type_name`: Change the `ValueType::type_name` implementation for using its own name. Generic arguments are rendered as the concrete types, e.g. `Id<User>`.
+/// - `type_name = "Name"`: Change the `ValueType::type_name` implementation for using the given string.
+/// - `column_type = "String(Some(64))"`: Change the `ValueType::column_type` implementation for returning the given `sea_query::ColumnType` variant.
+///   `Custom("citext")` is built by `ColumnType::custom`. It is used for the schema generated by `Schema::create_table_from_entity`.
+/// - `array_type = "String"`: Change the `ValueType::array_type` implementation for returning the given `sea_query::ArrayType` variant.
+/// - `derive_conversions`: With `from_into` or `try_from_into`, implement `From<OrmType> for NewType` and `From<NewType> for OrmType` through the base field.
+/// - `derive_conversions(validator = "path::fn", error = "ErrorType")`: With `try_from_into`, implement `TryFrom<OrmType> for NewType` by `fn(OrmType) -> Result<FieldType, ErrorType>` instead of `From`.
+/// - `error_kind = "std"`: Which trait the error of `TryFrom`, `FromStr` or `with` module implements. `"std"` (default) requires `std::error::Error + Send + Sync + 'static`
+///   to keep the error as the source of `DbErr` found by `sea_orm_newtype::find_source`, `"display"` requires only `Display` and keeps its message, and `"into_db_err"` requires `Into<DbErr>` and returns the converted `DbErr` as it is.
+/// - `map_err = "path::fn"`: Convert the error by `fn(E) -> DbErr` instead of `error_kind`.
+/// - `array_fallback(json)`: Generate `NewTypeArray(pub Vec<NewType>)` stored as a JSON array for the backends without native arrays.
+///   Each element is converted by the conversion of NewType through the `Value` of `OrmType`. Use `name = "Name"` to change the generated type name.
+///   `OrmType` must be a bool, an integer, a float, `String`, `char`, `Vec<u8>` (stored as hex), JSON, a chrono type, `Uuid`, `Decimal`, `BigDecimal` or `Option` of them (`None` is stored as `null`).
+/// - `array_fallback(delimiter = ",")`: Same as `array_fallback(json)`, but stored as a string joined by the delimiter, and `None` is stored as unquoted `NULL`.
+///   The element that is empty, `NULL`, contains the delimiter or `"`, or ends with a part of the delimiter is quoted like CSV, so the delimiter must not contain `"`.
+/// - `validate = "path::fn"`: Check each value read from the database by `fn(&NewType) -> Result<(), E>` in `TryGetable`, `ValueType::try_from` and `TryFromU64`.
+///   `E` is any type converted into `Box<dyn Error + Send + Sync>` such as `String`. It works with every conversion and does not affect the construction elsewhere.
+/// - `normalize = "path::fn"`: Normalize the value of `OrmType` by `fn(OrmType) -> OrmType` in `From<T> for sea_query::Value` before it is stored, so it can not be used with `skip(from)`.
+/// - `normalize(nfc, trim, lowercase)`: Normalize `String` by the built-ins applied in this order. `nfc` (Unicode Normalization Form C) requires `unicode` feature.
+/// - `redact`: Hide the raw value in `sea_orm_newtype::ConvertError`.
+/// - `rename_all = "snake_case"`: For enum, convert each variant name by the rule. `"lowercase"`, `"PascalCase"`, `"camelCase"`, `"snake_case"`, `"SCREAMING_SNAKE_CASE"` and `"kebab-case"` are available.
+/// - `bound = "T: Trait"`: Replace the inferred bounds of the generated impls by the given where predicates. `bound = ""` adds no bounds.
+/// - `skip(try_getable, nullable)`: Do not implement the listed traits so they can be implemented manually.
+///   `skip(try_getable)` also skips `TryGetableArray` with `postgres-array` feature, and can not be used with `array_fallback`.
+///   `from` (`From<T> for sea_query::Value`), `try_getable`, `value_type`, `nullable` and `into_active_value` are available.
+/// - `crate = "path::to::sea_orm_newtype"`: Refer to `sea_orm_newtype` by the given path instead of `::sea_orm_newtype`, e.g. when it is re-exported by another crate.
+///
+/// `OrmType` can be any type including paths and generics, e.g. `"uuid::Uuid"` or `"Vec<u8>"`.
+///
+/// Fieldless enum is converted into `String` by default. Each variant is stored as its name or `value`.
+/// If the enum has integer repr such as `#[repr(i16)]`, it is converted into the repr type by the explicit discriminants instead.
+///
+/// For generic NewType, if `OrmType` contains the type parameters (e.g. `transparent` for `Wrapper<T>(T)`),
+/// each impl requires `OrmType` to implement the corresponding trait. Otherwise no bounds are added, so use `bound` if needed.
+///
+/// With `postgres-array` feature, `sea_query::with_array::NotU8` and `sea_orm::TryGetableArray` are also implemented,
+/// so `Vec<NewType>` can be used as a Postgres array column if `Vec<OrmType>` can be.
+///
+/// # Variant Attributes
+/// - `value = "string"`: The value stored for the variant.
+/// - `other`: The variant such as `Other(String)` or `Unknown(i16)` holds an unknown value as it is, and it is written back unchanged.
+///
+/// # Field Attributes
+/// - `base`: Mark the base field for `transparent` or `derive_conversions`. It can be omitted if all the other fields are `PhantomData`.
+///
+/// # Errors
+/// An error of `TryFrom`, `FromStr` or `with` module in `TryGetable` and `TryFromU64` is kept as the `source` of `DbErr::TryIntoErr`,
+/// so it can be found by `sea_orm_newtype::find_source`. `ValueType::try_from` returns `ValueTypeErr` since it cannot hold any source.
+///
+/// With `error_kind = "into_db_err"` or `map_err`, the returned `DbErr` is used as it is. The error of `validate` is kept as the `source` in the same way.
+///
+/// In `TryGetable`, the error is wrapped by `sea_orm_newtype::ConvertError` with the column and the preview of the raw value.
 #[proc_macro_derive(DeriveNewType, attributes(sea_orm_newtype))]
 pub fn derive_new_type(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input_ast = parse_macro_input!(input as DeriveInput);
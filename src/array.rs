@@ -0,0 +1,296 @@
+use sea_orm::prelude::{
+    BigDecimal, ChronoDate, ChronoDateTime, ChronoDateTimeLocal, ChronoDateTimeUtc,
+    ChronoDateTimeWithTimeZone, ChronoTime, Decimal, Json, Uuid,
+};
+use sea_orm::sea_query::{ArrayType, Value};
+
+/// Format of `ChronoDateTime`. `Display` of it can not be parsed by `FromStr`.
+const DATE_TIME_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.f";
+
+/// Unquoted text of a null element in a delimited string.
+const NULL: &str = "NULL";
+
+/// Base type of `array_fallback`, whose values are written as the text parsed back by [`element_value`].
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(
+    message = "`array_fallback` does not support the elements of `{Self}`",
+    label = "`{Self}` can not be stored as an element of `array_fallback`",
+    note = "the base type must be a bool, an integer, a float, `String`, `char`, `Vec<u8>`, JSON, a chrono type, `Uuid`, `Decimal`, `BigDecimal` or `Option` of them"
+)]
+pub trait __ArrayElement {}
+
+macro_rules! impl_array_element {
+    ($($ty:ty),* $(,)?) => {
+        $(impl __ArrayElement for $ty {})*
+    };
+}
+
+impl_array_element!(
+    bool,
+    i8,
+    i16,
+    i32,
+    i64,
+    u8,
+    u16,
+    u32,
+    u64,
+    f32,
+    f64,
+    String,
+    char,
+    Vec<u8>,
+    Json,
+    ChronoDate,
+    ChronoTime,
+    ChronoDateTime,
+    ChronoDateTimeUtc,
+    ChronoDateTimeLocal,
+    ChronoDateTimeWithTimeZone,
+    Uuid,
+    Decimal,
+    BigDecimal,
+);
+
+impl<T: __ArrayElement> __ArrayElement for Option<T> {}
+
+/// Text of an element that is parsed back by [`element_value`]. `None` if the value is null.
+///
+/// # Panics
+/// Panics if the type of the value does not implement [`__ArrayElement`].
+fn element_text(value: Value) -> Option<String> {
+    match value {
+        Value::Bool(v) => v.map(|v| v.to_string()),
+        Value::TinyInt(v) => v.map(|v| v.to_string()),
+        Value::SmallInt(v) => v.map(|v| v.to_string()),
+        Value::Int(v) => v.map(|v| v.to_string()),
+        Value::BigInt(v) => v.map(|v| v.to_string()),
+        Value::TinyUnsigned(v) => v.map(|v| v.to_string()),
+        Value::SmallUnsigned(v) => v.map(|v| v.to_string()),
+        Value::Unsigned(v) => v.map(|v| v.to_string()),
+        Value::BigUnsigned(v) => v.map(|v| v.to_string()),
+        Value::Float(v) => v.map(|v| v.to_string()),
+        Value::Double(v) => v.map(|v| v.to_string()),
+        Value::String(v) => v.map(|v| *v),
+        Value::Char(v) => v.map(|v| v.to_string()),
+        Value::Bytes(v) => v.map(|v| v.iter().map(|b| format!("{b:02x}")).collect()),
+        Value::Json(v) => v.map(|v| v.to_string()),
+        Value::ChronoDate(v) => v.map(|v| v.to_string()),
+        Value::ChronoTime(v) => v.map(|v| v.to_string()),
+        Value::ChronoDateTime(v) => v.map(|v| v.format(DATE_TIME_FORMAT).to_string()),
+        Value::ChronoDateTimeUtc(v) => v.map(|v| v.to_rfc3339()),
+        Value::ChronoDateTimeLocal(v) => v.map(|v| v.to_rfc3339()),
+        Value::ChronoDateTimeWithTimeZone(v) => v.map(|v| v.to_rfc3339()),
+        Value::Uuid(v) => v.map(|v| v.to_string()),
+        Value::Decimal(v) => v.map(|v| v.to_string()),
+        Value::BigDecimal(v) => v.map(|v| v.to_string()),
+        value => panic!("`array_fallback` does not support the element {value:?}"),
+    }
+}
+
+/// Value of `array_type` parsed from the text of an element, or the null value of it if `text` is `None`.
+fn element_value(text: Option<&str>, array_type: &ArrayType) -> Result<Value, String> {
+    fn parse<T: std::str::FromStr>(text: Option<&str>) -> Result<Option<T>, String>
+    where
+        T::Err: std::fmt::Display,
+    {
+        text.map(|text| {
+            text.parse::<T>()
+                .map_err(|e| format!("invalid element {text:?}: {e}"))
+        })
+        .transpose()
+    }
+
+    let value = match array_type {
+        ArrayType::Bool => Value::from(parse::<bool>(text)?),
+        ArrayType::TinyInt => Value::from(parse::<i8>(text)?),
+        ArrayType::SmallInt => Value::from(parse::<i16>(text)?),
+        ArrayType::Int => Value::from(parse::<i32>(text)?),
+        ArrayType::BigInt => Value::from(parse::<i64>(text)?),
+        ArrayType::TinyUnsigned => Value::from(parse::<u8>(text)?),
+        ArrayType::SmallUnsigned => Value::from(parse::<u16>(text)?),
+        ArrayType::Unsigned => Value::from(parse::<u32>(text)?),
+        ArrayType::BigUnsigned => Value::from(parse::<u64>(text)?),
+        ArrayType::Float => Value::from(parse::<f32>(text)?),
+        ArrayType::Double => Value::from(parse::<f64>(text)?),
+        ArrayType::String => Value::from(text.map(str::to_owned)),
+        ArrayType::Char => Value::from(parse::<char>(text)?),
+        ArrayType::Bytes => Value::from(text.map(decode_hex).transpose()?),
+        ArrayType::Json => Value::from(parse::<Json>(text)?),
+        ArrayType::ChronoDate => Value::from(parse::<ChronoDate>(text)?),
+        ArrayType::ChronoTime => Value::from(parse::<ChronoTime>(text)?),
+        ArrayType::ChronoDateTime => Value::from(
+            text.map(|text| {
+                ChronoDateTime::parse_from_str(text, DATE_TIME_FORMAT)
+                    .map_err(|e| format!("invalid element {text:?}: {e}"))
+            })
+            .transpose()?,
+        ),
+        ArrayType::ChronoDateTimeUtc => {
+            Value::from(parse::<ChronoDateTimeWithTimeZone>(text)?.map(ChronoDateTimeUtc::from))
+        }
+        ArrayType::ChronoDateTimeLocal => {
+            Value::from(parse::<ChronoDateTimeWithTimeZone>(text)?.map(ChronoDateTimeLocal::from))
+        }
+        ArrayType::ChronoDateTimeWithTimeZone => {
+            Value::from(parse::<ChronoDateTimeWithTimeZone>(text)?)
+        }
+        ArrayType::Uuid => Value::from(parse::<Uuid>(text)?),
+        ArrayType::Decimal => Value::from(parse::<Decimal>(text)?),
+        ArrayType::BigDecimal => Value::from(parse::<BigDecimal>(text)?),
+        _ => return Err(format!("unsupported element type {array_type:?}")),
+    };
+    Ok(value)
+}
+
+/// Bytes written as lowercase hex digits by [`element_text`].
+fn decode_hex(text: &str) -> Result<Vec<u8>, String> {
+    if !text.len().is_multiple_of(2) || !text.is_ascii() {
+        return Err(format!("invalid hex element {text:?}"));
+    }
+    (0..text.len())
+        .step_by(2)
+        .map(|i| {
+            u8::from_str_radix(&text[i..i + 2], 16)
+                .map_err(|e| format!("invalid hex element {text:?}: {e}"))
+        })
+        .collect()
+}
+
+/// Encode the values of elements of `T` as a JSON array.
+///
+/// Booleans, numbers and JSON are written as they are, nulls are written as `null`, and the others are written as strings.
+#[doc(hidden)]
+pub fn __encode_json_array<T: __ArrayElement>(values: impl IntoIterator<Item = Value>) -> Value {
+    let elements = values
+        .into_iter()
+        .map(|value| match value {
+            Value::Bool(Some(v)) => Json::from(v),
+            Value::TinyInt(Some(v)) => Json::from(v),
+            Value::SmallInt(Some(v)) => Json::from(v),
+            Value::Int(Some(v)) => Json::from(v),
+            Value::BigInt(Some(v)) => Json::from(v),
+            Value::TinyUnsigned(Some(v)) => Json::from(v),
+            Value::SmallUnsigned(Some(v)) => Json::from(v),
+            Value::Unsigned(Some(v)) => Json::from(v),
+            Value::BigUnsigned(Some(v)) => Json::from(v),
+            // NaN and infinity can not be written as JSON numbers, so they are written as strings
+            Value::Float(Some(v)) if v.is_finite() => Json::from(v),
+            Value::Double(Some(v)) if v.is_finite() => Json::from(v),
+            Value::Json(Some(v)) => *v,
+            value => element_text(value).map_or(Json::Null, Json::String),
+        })
+        .collect();
+    Value::Json(Some(Box::new(Json::Array(elements))))
+}
+
+/// Encode the values of elements of `T` as a string joined by `delimiter`.
+///
+/// The element that needs to be distinguished from the others is quoted with `"`, and `"` in it is doubled (see [`needs_quote`]).
+/// So `[]` is written as ``, `[""]` is written as `""`, and null is written as unquoted `NULL`.
+#[doc(hidden)]
+pub fn __encode_delimited<T: __ArrayElement>(
+    values: impl IntoIterator<Item = Value>,
+    delimiter: &str,
+) -> Value {
+    let texts = values
+        .into_iter()
+        .map(|value| match element_text(value) {
+            Some(text) if needs_quote(&text, delimiter) => {
+                format!("\"{}\"", text.replace('"', "\"\""))
+            }
+            Some(text) => text,
+            None => NULL.to_owned(),
+        })
+        .collect::<Vec<_>>();
+    Value::String(Some(Box::new(texts.join(delimiter))))
+}
+
+/// Whether the text of an element must be quoted to be split back by [`split_delimited`].
+///
+/// Besides the empty text, `NULL` and the text containing `delimiter` or `"`, the text ending with a part of
+/// a multi-character delimiter is quoted, e.g. `a,` followed by `,,` would be split as `a` and `,`.
+fn needs_quote(text: &str, delimiter: &str) -> bool {
+    text.is_empty()
+        || text == NULL
+        || text.contains(delimiter)
+        || text.contains('"')
+        || (1..delimiter.len())
+            .any(|i| delimiter.is_char_boundary(i) && text.ends_with(&delimiter[..i]))
+}
+
+/// Decode a JSON array into the values of elements of `array_type`.
+#[doc(hidden)]
+pub fn __decode_json_array(json: Json, array_type: ArrayType) -> Result<Vec<Value>, String> {
+    let Json::Array(elements) = json else {
+        return Err(format!("expected JSON array, found {json}"));
+    };
+    elements
+        .into_iter()
+        .map(|element| match (element, &array_type) {
+            (element, ArrayType::Json) => Ok(Value::from(element)),
+            (Json::Null, _) => element_value(None, &array_type),
+            (Json::String(text), _) => element_value(Some(&text), &array_type),
+            (element @ (Json::Number(_) | Json::Bool(_)), _) => {
+                element_value(Some(&element.to_string()), &array_type)
+            }
+            (element, _) => Err(format!("unexpected element {element}")),
+        })
+        .collect()
+}
+
+/// Decode a string joined by `delimiter` into the values of elements of `array_type`.
+#[doc(hidden)]
+pub fn __decode_delimited(
+    text: &str,
+    delimiter: &str,
+    array_type: ArrayType,
+) -> Result<Vec<Value>, String> {
+    split_delimited(text, delimiter)?
+        .iter()
+        .map(|text| element_value(text.as_deref(), &array_type))
+        .collect()
+}
+
+/// Split a string written by [`__encode_delimited`] into the texts of elements. `None` for unquoted `NULL`.
+fn split_delimited(text: &str, delimiter: &str) -> Result<Vec<Option<String>>, String> {
+    let mut texts = Vec::new();
+    if text.is_empty() {
+        return Ok(texts);
+    }
+
+    let mut rest = text;
+    loop {
+        let (element, after) = if let Some(quoted) = rest.strip_prefix('"') {
+            // find the closing `"` while skipping the doubled `""`
+            let mut element = String::new();
+            let mut chars = quoted.char_indices();
+            let end = loop {
+                match chars.next() {
+                    Some((i, '"')) if quoted[i + 1..].starts_with('"') => {
+                        element.push('"');
+                        chars.next();
+                    }
+                    Some((i, '"')) => break i + 1,
+                    Some((_, c)) => element.push(c),
+                    None => return Err(format!("unterminated quote in {text:?}")),
+                }
+            };
+            let after = &quoted[end..];
+            if !after.is_empty() && !after.starts_with(delimiter) {
+                return Err(format!("unexpected text after quote in {text:?}"));
+            }
+            (Some(element), after)
+        } else {
+            let end = rest.find(delimiter).unwrap_or(rest.len());
+            let element = &rest[..end];
+            ((element != NULL).then(|| element.to_owned()), &rest[end..])
+        };
+        texts.push(element);
+
+        match after.strip_prefix(delimiter) {
+            Some(next) => rest = next,
+            None => return Ok(texts),
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use std::error::Error;
+use std::fmt::Display;
+
+use sea_orm::{DbErr, TryFromU64};
+
+/// Error type of `error_kind = "std"`.
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` must implement `std::error::Error + Send + Sync + 'static` for `error_kind = \"std\"`",
+    label = "`{Self}` is not `std::error::Error + Send + Sync + 'static`",
+    note = "implement `std::error::Error` for `{Self}`, or choose another `error_kind` or `map_err`"
+)]
+pub trait __StdError: Error + Send + Sync + 'static {}
+
+impl<T: Error + Send + Sync + 'static> __StdError for T {}
+
+/// Base type of `primary_key`.
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(
+    message = "`primary_key` requires the base type `{Self}` to implement `sea_orm::TryFromU64`",
+    label = "`{Self}` does not implement `sea_orm::TryFromU64`",
+    note = "derive `DeriveNewType` for `{Self}` with `primary_key`, or implement `sea_orm::TryFromU64` for it"
+)]
+pub trait __PrimaryKey: TryFromU64 {}
+
+impl<T: TryFromU64> __PrimaryKey for T {}
+
+/// Box the error of `error_kind = "std"` as the source of `DbErr`.
+#[doc(hidden)]
+pub fn __std_source<E: __StdError>(e: E) -> Box<dyn Error + Send + Sync> {
+    Box::new(e)
+}
+
+/// Render the error of `error_kind = "display"` as the source of `DbErr`.
+#[doc(hidden)]
+pub fn __display_source<E: Display>(e: E) -> String {
+    e.to_string()
+}
+
+/// Convert the error of `error_kind = "into_db_err"` into `DbErr`.
+#[doc(hidden)]
+pub fn __into_db_err<E: Into<DbErr>>(e: E) -> DbErr {
+    e.into()
+}
+
+/// Read the base type of `primary_key`.
+#[doc(hidden)]
+pub fn __try_from_u64<T: __PrimaryKey>(n: u64) -> Result<T, DbErr> {
+    T::try_from_u64(n)
+}
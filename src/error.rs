@@ -0,0 +1,122 @@
+use std::error::Error;
+use std::fmt::{Debug, Display};
+
+use sea_orm::{ColIdx, DbErr, QueryResult, TryGetable};
+
+/// Max length of the raw value shown in [`ConvertError`].
+const PREVIEW_LEN: usize = 64;
+
+/// Error of converting a value read from the database into the new type.
+///
+/// It is held as the `source` of `DbErr::TryIntoErr` returned from the generated `TryGetable` implementation.
+#[derive(Debug)]
+pub struct ConvertError {
+    type_name: &'static str,
+    column: Column,
+    value: Option<String>,
+    source: Box<dyn Error + Send + Sync>,
+}
+
+/// Column specified by `ColIdx`.
+#[derive(Debug)]
+enum Column {
+    Name(String),
+    Index(String),
+}
+
+impl ConvertError {
+    /// Name of the new type.
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// Column name, or index such as `0`.
+    pub fn column(&self) -> &str {
+        match &self.column {
+            Column::Name(name) => name,
+            Column::Index(index) => index,
+        }
+    }
+
+    /// Preview of the raw database value. `None` if it is redacted.
+    pub fn value(&self) -> Option<&str> {
+        self.value.as_deref()
+    }
+
+    /// Build `DbErr` for `T::try_get_by` failed to convert the value of `B`.
+    #[doc(hidden)]
+    pub fn __try_get_err<T, B, I>(
+        res: &QueryResult,
+        index: I,
+        redact: bool,
+        source: impl Into<Box<dyn Error + Send + Sync>>,
+    ) -> DbErr
+    where
+        B: TryGetable + Debug,
+        I: ColIdx,
+    {
+        let column = match (index.as_str(), index.as_usize()) {
+            (Some(name), _) => Column::Name(name.to_owned()),
+            (None, Some(i)) => Column::Index(i.to_string()),
+            (None, None) => Column::Name(format!("{index:?}")),
+        };
+
+        // the value has been consumed by the conversion, so read it again only on failure
+        let value = (!redact)
+            .then(|| B::try_get_by(res, index).ok())
+            .flatten()
+            .map(|value| {
+                let mut preview = format!("{value:?}");
+                if let Some((end, _)) = preview.char_indices().nth(PREVIEW_LEN) {
+                    preview.truncate(end);
+                    preview.push('…');
+                }
+                preview
+            });
+
+        DbErr::TryIntoErr {
+            from: std::any::type_name::<B>(),
+            into: std::any::type_name::<T>(),
+            source: Box::new(ConvertError {
+                type_name: std::any::type_name::<T>(),
+                column,
+                value,
+                source: source.into(),
+            }),
+        }
+    }
+}
+
+impl Display for ConvertError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.column {
+            Column::Name(name) => write!(f, "column `{name}` ")?,
+            Column::Index(index) => write!(f, "column #{index} ")?,
+        }
+        match &self.value {
+            Some(value) => write!(f, "(value: {value})")?,
+            None => write!(f, "(value: <redacted>)")?,
+        }
+        write!(f, ": {}", self.source)
+    }
+}
+
+impl Error for ConvertError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(self.source.as_ref())
+    }
+}
+
+/// Find the error of type `E` from the source chain of `err`.
+///
+/// It can be used to get the original conversion error (e.g. `TryFrom::Error`) from `DbErr`.
+pub fn find_source<E: Error + 'static>(err: &DbErr) -> Option<&E> {
+    let mut current: Option<&(dyn Error + 'static)> = Some(err);
+    while let Some(e) = current {
+        if let Some(found) = e.downcast_ref::<E>() {
+            return Some(found);
+        }
+        current = e.source();
+    }
+    None
+}
@@ -24,11 +24,39 @@
 //!         value.0
 //!     }
 //! }
+//! # fn main() {}
 //! ```
 
+mod array;
+mod bound;
+mod error;
+mod normalize;
+mod type_name;
+
+#[doc(hidden)]
+pub use array::{
+    __ArrayElement, __decode_delimited, __decode_json_array, __encode_delimited,
+    __encode_json_array,
+};
+#[doc(hidden)]
+pub use bound::{
+    __PrimaryKey, __StdError, __display_source, __into_db_err, __std_source, __try_from_u64,
+};
+pub use error::{find_source, ConvertError};
+#[cfg(feature = "unicode")]
+#[doc(hidden)]
+pub use normalize::__normalize_nfc;
+#[doc(hidden)]
+pub use normalize::{__normalize_lowercase, __normalize_trim};
+#[doc(hidden)]
+pub use type_name::__short_type_name;
+
 pub use sea_orm;
 pub use sea_orm::sea_query;
 
+#[cfg(feature = "serde")]
+pub use serde_json;
+
 pub use sea_orm::{
     sea_query::{value::Nullable, ValueType},
     TryFromU64, TryGetable, Value,
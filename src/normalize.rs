@@ -0,0 +1,29 @@
+/// Remove the leading and trailing whitespace.
+#[doc(hidden)]
+pub fn __normalize_trim(value: String) -> String {
+    let trimmed = value.trim();
+    if trimmed.len() == value.len() {
+        value
+    } else {
+        trimmed.to_owned()
+    }
+}
+
+/// Convert into lowercase.
+#[doc(hidden)]
+pub fn __normalize_lowercase(value: String) -> String {
+    value.to_lowercase()
+}
+
+/// Convert into Unicode Normalization Form C.
+#[cfg(feature = "unicode")]
+#[doc(hidden)]
+pub fn __normalize_nfc(value: String) -> String {
+    use unicode_normalization::{is_nfc_quick, IsNormalized, UnicodeNormalization};
+
+    if is_nfc_quick(value.chars()) == IsNormalized::Yes {
+        value
+    } else {
+        value.nfc().collect()
+    }
+}
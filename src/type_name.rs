@@ -0,0 +1,20 @@
+/// Render `std::any::type_name::<T>()` without module paths, e.g. `Id<User>` instead of `my_crate::Id<my_crate::User>`.
+#[doc(hidden)]
+pub fn __short_type_name<T: ?Sized>() -> String {
+    let full_name = std::any::type_name::<T>();
+
+    let mut short_name = String::with_capacity(full_name.len());
+    let mut path = String::new();
+    for c in full_name.chars() {
+        if c.is_alphanumeric() || c == '_' || c == ':' {
+            path.push(c);
+        } else {
+            short_name.push_str(path.rsplit("::").next().unwrap_or_default());
+            path.clear();
+            short_name.push(c);
+        }
+    }
+    short_name.push_str(path.rsplit("::").next().unwrap_or_default());
+
+    short_name
+}
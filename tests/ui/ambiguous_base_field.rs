@@ -0,0 +1,9 @@
+use sea_orm_newtype::DeriveNewType;
+
+#[derive(Clone, Debug, PartialEq, DeriveNewType)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+fn main() {}
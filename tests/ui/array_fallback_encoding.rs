@@ -0,0 +1,7 @@
+use sea_orm_newtype::DeriveNewType;
+
+#[derive(Clone, Debug, PartialEq, DeriveNewType)]
+#[sea_orm_newtype(array_fallback(json, delimiter = ","))]
+pub struct Tag(String);
+
+fn main() {}
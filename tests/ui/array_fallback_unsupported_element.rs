@@ -0,0 +1,7 @@
+use sea_orm_newtype::DeriveNewType;
+
+#[derive(Clone, Debug, PartialEq, DeriveNewType)]
+#[sea_orm_newtype(transparent, array_fallback(json))]
+pub struct Day(sea_orm::prelude::TimeDate);
+
+fn main() {}
@@ -0,0 +1,7 @@
+use sea_orm_newtype::DeriveNewType;
+
+#[derive(Clone, Debug, PartialEq, DeriveNewType)]
+#[sea_orm_newtype(base_type = "String")]
+pub struct Name(String);
+
+fn main() {}
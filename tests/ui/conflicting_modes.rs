@@ -0,0 +1,7 @@
+use sea_orm_newtype::DeriveNewType;
+
+#[derive(Clone, Debug, PartialEq, DeriveNewType)]
+#[sea_orm_newtype(from_into = "String", via_str)]
+pub struct Name(String);
+
+fn main() {}
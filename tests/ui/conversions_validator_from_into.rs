@@ -0,0 +1,14 @@
+use sea_orm_newtype::DeriveNewType;
+
+#[derive(Clone, Debug, PartialEq, DeriveNewType)]
+#[sea_orm_newtype(
+    from_into = "String",
+    derive_conversions(validator = "validate", error = "String")
+)]
+pub struct Name(String);
+
+fn validate(value: String) -> Result<String, String> {
+    Ok(value)
+}
+
+fn main() {}
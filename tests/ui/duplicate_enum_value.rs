@@ -0,0 +1,10 @@
+use sea_orm_newtype::DeriveNewType;
+
+#[derive(Clone, Debug, PartialEq, DeriveNewType)]
+pub enum Status {
+    Active,
+    #[sea_orm_newtype(value = "Active")]
+    Enabled,
+}
+
+fn main() {}
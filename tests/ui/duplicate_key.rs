@@ -0,0 +1,7 @@
+use sea_orm_newtype::DeriveNewType;
+
+#[derive(Clone, Debug, PartialEq, DeriveNewType)]
+#[sea_orm_newtype(from_into = "i32", from_into = "i64")]
+pub struct Id(i32);
+
+fn main() {}
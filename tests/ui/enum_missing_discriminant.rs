@@ -0,0 +1,10 @@
+use sea_orm_newtype::DeriveNewType;
+
+#[derive(Clone, Debug, PartialEq, DeriveNewType)]
+#[repr(i16)]
+pub enum Priority {
+    Low = 0,
+    High,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use sea_orm_newtype::DeriveNewType;
+
+#[derive(Clone, Debug, PartialEq, DeriveNewType)]
+pub enum Status {
+    Active,
+    Suspended(String),
+}
+
+fn main() {}
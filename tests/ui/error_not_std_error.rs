@@ -0,0 +1,28 @@
+use sea_orm_newtype::DeriveNewType;
+
+#[derive(Debug)]
+pub struct Negative;
+
+#[derive(Clone, Debug, PartialEq, DeriveNewType)]
+#[sea_orm_newtype(try_from_into = "i32")]
+pub struct Count(i32);
+
+impl TryFrom<i32> for Count {
+    type Error = Negative;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        if value < 0 {
+            Err(Negative)
+        } else {
+            Ok(Count(value))
+        }
+    }
+}
+
+impl From<Count> for i32 {
+    fn from(value: Count) -> Self {
+        value.0
+    }
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+use sea_orm_newtype::DeriveNewType;
+
+#[derive(Clone, Debug, PartialEq, DeriveNewType)]
+#[sea_orm_newtype(via_str, map_err = "to_db_err", error_kind = "display")]
+pub struct Name(String);
+
+fn main() {}
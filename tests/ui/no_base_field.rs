@@ -0,0 +1,8 @@
+use std::marker::PhantomData;
+
+use sea_orm_newtype::DeriveNewType;
+
+#[derive(Clone, Debug, PartialEq, DeriveNewType)]
+pub struct Marker(PhantomData<i32>);
+
+fn main() {}
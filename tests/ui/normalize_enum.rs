@@ -0,0 +1,10 @@
+use sea_orm_newtype::DeriveNewType;
+
+#[derive(Clone, Debug, PartialEq, DeriveNewType)]
+#[sea_orm_newtype(normalize(lowercase))]
+pub enum Status {
+    Active,
+    Inactive,
+}
+
+fn main() {}
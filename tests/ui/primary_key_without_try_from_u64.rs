@@ -0,0 +1,10 @@
+use sea_orm_newtype::DeriveNewType;
+
+#[derive(Clone, Debug, PartialEq, DeriveNewType)]
+pub struct Inner(i32);
+
+#[derive(Clone, Debug, PartialEq, DeriveNewType)]
+#[sea_orm_newtype(transparent, primary_key)]
+pub struct Outer(Inner);
+
+fn main() {}
@@ -0,0 +1,12 @@
+use std::marker::PhantomData;
+
+use sea_orm_newtype::DeriveNewType;
+
+#[derive(Clone, Debug, PartialEq, DeriveNewType)]
+pub struct Id<T> {
+    #[sea_orm_newtype(bas)]
+    value: i32,
+    _marker: PhantomData<T>,
+}
+
+fn main() {}
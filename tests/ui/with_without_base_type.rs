@@ -0,0 +1,9 @@
+use sea_orm_newtype::DeriveNewType;
+
+#[derive(Clone, Debug, PartialEq, DeriveNewType)]
+#[sea_orm_newtype(with = "codec")]
+pub struct Name(String);
+
+mod codec {}
+
+fn main() {}